@@ -0,0 +1,22 @@
+use web_sys::{Document, Element};
+
+/// Frame elements containing the document in same-origin parent documents, from the innermost outwards.
+pub fn frame_elements(document: &Document) -> Vec<Element> {
+    let mut frame_elements = vec![];
+    let mut current_document = document.clone();
+
+    // `frameElement` is `null` for top-level and cross-origin documents.
+    while let Some(frame_element) = current_document
+        .default_view()
+        .and_then(|window| window.frame_element().ok().flatten())
+    {
+        let Some(owner_document) = frame_element.owner_document() else {
+            break;
+        };
+
+        frame_elements.push(frame_element);
+        current_document = owner_document;
+    }
+
+    frame_elements
+}
@@ -0,0 +1,154 @@
+use std::{cell::Cell, hash::Hash};
+
+use web_sys::{
+    DocumentFragment, Element, Node, ShadowRoot, console,
+    js_sys::WeakMap,
+    wasm_bindgen::{JsCast, JsValue},
+};
+
+use crate::util::{html_collection_to_elements, node_list_to_elements};
+
+/// DOM operations needed to suppress elements, so the bookkeeping does not depend on a browser.
+///
+/// Internal to the crate: the public API always uses [`WebDom`], the in-memory implementation only exists for tests.
+pub trait Dom {
+    type Node: Clone + PartialEq;
+    type Key: Copy + Eq + Hash;
+    type Error;
+
+    /// Stable identity of the node, for hashing nodes which do not implement `Hash`.
+    fn key(&self, node: &Self::Node) -> Self::Key;
+
+    /// Parent of the node, `None` for roots such as documents and shadow roots.
+    fn parent(&self, node: &Self::Node) -> Option<Self::Node>;
+
+    /// Element children of the node.
+    fn children(&self, node: &Self::Node) -> Vec<Self::Node>;
+
+    /// Whether `other` is an inclusive descendant of the node, without crossing shadow boundaries.
+    fn contains(&self, node: &Self::Node, other: &Self::Node) -> bool;
+
+    fn get_attribute(&self, node: &Self::Node, name: &str) -> Option<String>;
+
+    fn set_attribute(&self, node: &Self::Node, name: &str, value: &str) -> Result<(), Self::Error>;
+
+    fn remove_attribute(&self, node: &Self::Node, name: &str) -> Result<(), Self::Error>;
+
+    /// Descendant elements of the node matching the selectors, in tree order.
+    fn query_selector_all(
+        &self,
+        node: &Self::Node,
+        selectors: &str,
+    ) -> Result<Vec<Self::Node>, Self::Error>;
+
+    /// Host of the node, if it is a shadow root.
+    fn shadow_host(&self, node: &Self::Node) -> Option<Self::Node>;
+
+    /// Open shadow root of the node, if it is a shadow host.
+    fn shadow_root(&self, node: &Self::Node) -> Option<Self::Node>;
+
+    /// Reports an error which does not stop the other nodes from being suppressed or restored.
+    fn report_error(&self, message: &str, node: &Self::Node, error: Self::Error);
+}
+
+// Node is not Send and WebAssembly is single threaded, so this should be fine for now.
+thread_local! {
+    static NODE_KEYS: WeakMap = WeakMap::new();
+    static NEXT_NODE_KEY: Cell<u64> = const { Cell::new(0) };
+}
+
+/// [`Dom`] implementation for the browser.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WebDom;
+
+impl Dom for WebDom {
+    type Node = Node;
+    type Key = u64;
+    type Error = JsValue;
+
+    fn key(&self, node: &Node) -> u64 {
+        NODE_KEYS.with(|node_keys| {
+            if let Some(key) = node_keys.get(node).as_f64() {
+                return key as u64;
+            }
+
+            let key = NEXT_NODE_KEY.get();
+            NEXT_NODE_KEY.set(key + 1);
+            node_keys.set(node, &JsValue::from_f64(key as f64));
+            key
+        })
+    }
+
+    fn parent(&self, node: &Node) -> Option<Node> {
+        node.parent_node()
+    }
+
+    fn children(&self, node: &Node) -> Vec<Node> {
+        // Shadow roots are document fragments as well.
+        let children = if let Some(element) = node.dyn_ref::<Element>() {
+            element.children()
+        } else if let Some(document_fragment) = node.dyn_ref::<DocumentFragment>() {
+            document_fragment.children()
+        } else {
+            return vec![];
+        };
+
+        html_collection_to_elements(children)
+            .into_iter()
+            .map(Node::from)
+            .collect()
+    }
+
+    fn contains(&self, node: &Node, other: &Node) -> bool {
+        node.contains(Some(other))
+    }
+
+    fn get_attribute(&self, node: &Node, name: &str) -> Option<String> {
+        node.dyn_ref::<Element>()
+            .and_then(|element| element.get_attribute(name))
+    }
+
+    fn set_attribute(&self, node: &Node, name: &str, value: &str) -> Result<(), JsValue> {
+        match node.dyn_ref::<Element>() {
+            Some(element) => element.set_attribute(name, value),
+            None => Ok(()),
+        }
+    }
+
+    fn remove_attribute(&self, node: &Node, name: &str) -> Result<(), JsValue> {
+        match node.dyn_ref::<Element>() {
+            Some(element) => element.remove_attribute(name),
+            None => Ok(()),
+        }
+    }
+
+    fn query_selector_all(&self, node: &Node, selectors: &str) -> Result<Vec<Node>, JsValue> {
+        let node_list = if let Some(element) = node.dyn_ref::<Element>() {
+            element.query_selector_all(selectors)?
+        } else if let Some(document_fragment) = node.dyn_ref::<DocumentFragment>() {
+            document_fragment.query_selector_all(selectors)?
+        } else {
+            return Ok(vec![]);
+        };
+
+        Ok(node_list_to_elements(node_list)
+            .into_iter()
+            .map(Node::from)
+            .collect())
+    }
+
+    fn shadow_host(&self, node: &Node) -> Option<Node> {
+        node.dyn_ref::<ShadowRoot>()
+            .map(|shadow_root| shadow_root.host().into())
+    }
+
+    fn shadow_root(&self, node: &Node) -> Option<Node> {
+        node.dyn_ref::<Element>()
+            .and_then(|element| element.shadow_root())
+            .map(Node::from)
+    }
+
+    fn report_error(&self, message: &str, node: &Node, error: JsValue) {
+        console::error_3(&message.into(), node, &error);
+    }
+}
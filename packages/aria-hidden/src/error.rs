@@ -0,0 +1,37 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+/// Error returned when elements can not be suppressed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AriaHiddenError {
+    /// No target elements were provided.
+    EmptyTargets,
+    /// None of the targets is inside the parent node, e.g. because they are not connected to the document yet.
+    DetachedTargets,
+    /// No parent node was provided and the document of the targets has no body.
+    MissingBody,
+    /// The marker name is not a valid attribute name.
+    InvalidMarkerName(String),
+}
+
+impl Display for AriaHiddenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AriaHiddenError::EmptyTargets => write!(f, "At least one target element is required."),
+            AriaHiddenError::DetachedTargets => {
+                write!(f, "None of the target elements is inside the parent node.")
+            }
+            AriaHiddenError::MissingBody => write!(f, "The document has no body."),
+            AriaHiddenError::InvalidMarkerName(marker_name) => {
+                write!(
+                    f,
+                    "Marker name `{marker_name}` is not a valid attribute name."
+                )
+            }
+        }
+    }
+}
+
+impl Error for AriaHiddenError {}
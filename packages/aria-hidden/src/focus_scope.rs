@@ -0,0 +1,409 @@
+use std::{cell::RefCell, cmp::Ordering, rc::Rc};
+
+use web_sys::{
+    Document, Element, FocusEvent, HtmlElement, KeyboardEvent, Node, console,
+    wasm_bindgen::{JsCast, closure::Closure},
+};
+
+use crate::{
+    AriaHiddenError, Guard, SuppressOthersOptions, inert_others, util::node_list_to_elements,
+};
+
+// https://html.spec.whatwg.org/multipage/interaction.html#focusable-area
+const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]):not([type=\"hidden\"]), select:not([disabled]), textarea:not([disabled]), iframe, object, embed, summary, [contenteditable]:not([contenteditable=\"false\"]), [tabindex]";
+
+type Listeners = (Closure<dyn Fn(KeyboardEvent)>, Closure<dyn Fn(FocusEvent)>);
+
+struct FocusScope {
+    targets: Vec<Element>,
+    previously_focused: RefCell<Option<HtmlElement>>,
+    /// Event listeners registered on the document, owned by the scope so they live as long as their registration.
+    listeners: RefCell<Option<Listeners>>,
+}
+
+impl FocusScope {
+    fn contains(&self, element: &Element) -> bool {
+        self.targets
+            .iter()
+            .any(|target| target.contains(Some(element)))
+    }
+
+    /// Elements in the scope reachable with Tab, in sequential focus navigation order.
+    fn tabbable_elements(&self) -> Vec<HtmlElement> {
+        let mut elements = vec![];
+
+        for target in &self.targets {
+            if target.matches(FOCUSABLE_SELECTOR).unwrap_or(false) {
+                elements.push(target.clone());
+            }
+            if let Ok(node_list) = target.query_selector_all(FOCUSABLE_SELECTOR) {
+                elements.extend(node_list_to_elements(node_list));
+            }
+        }
+
+        let mut elements = elements
+            .into_iter()
+            .filter_map(|element| element.dyn_into::<HtmlElement>().ok())
+            .filter(|element| {
+                element.tab_index() >= 0
+                    && element
+                        .closest("[inert], [hidden]")
+                        .ok()
+                        .flatten()
+                        .is_none()
+                    && is_rendered(element)
+            })
+            .collect::<Vec<_>>();
+
+        sort_tab_order(&mut elements, tree_order, HtmlElement::tab_index);
+        elements
+    }
+
+    fn focus_first(&self) {
+        if let Some(element) = self.tabbable_elements().first() {
+            focus(element);
+        } else if let Some(target) = self
+            .targets
+            .first()
+            .and_then(|target| target.dyn_ref::<HtmlElement>())
+        {
+            focus(target);
+        }
+    }
+}
+
+// Element is not Send and WebAssembly is single threaded, so this should be fine for now.
+thread_local! {
+    static FOCUS_SCOPES: RefCell<Vec<Rc<FocusScope>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Whether the element is rendered and visible, i.e. not in a `display: none` subtree and not `visibility: hidden`.
+fn is_rendered(element: &HtmlElement) -> bool {
+    if element.get_client_rects().length() == 0 {
+        return false;
+    }
+
+    element
+        .owner_document()
+        .and_then(|document| document.default_view())
+        .and_then(|window| window.get_computed_style(element).ok().flatten())
+        .and_then(|style| style.get_property_value("visibility").ok())
+        .is_none_or(|visibility| visibility != "hidden" && visibility != "collapse")
+}
+
+fn tree_order(a: &HtmlElement, b: &HtmlElement) -> Ordering {
+    if a == b {
+        Ordering::Equal
+    } else if a.compare_document_position(b) & Node::DOCUMENT_POSITION_FOLLOWING != 0 {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+/// Sorts elements in sequential focus navigation order and removes duplicates, such as elements in nested targets.
+///
+/// Elements with a positive tab index come first in ascending order, followed by the others in tree order.
+/// See <https://html.spec.whatwg.org/multipage/interaction.html#sequential-focus-navigation-order>.
+fn sort_tab_order<T: PartialEq>(
+    elements: &mut Vec<T>,
+    tree_order: impl Fn(&T, &T) -> Ordering,
+    tab_index: impl Fn(&T) -> i32,
+) {
+    elements.sort_by(&tree_order);
+    elements.dedup();
+    // Stable, so elements with the same tab index stay in tree order.
+    elements.sort_by_key(|element| match tab_index(element) {
+        0 => i32::MAX,
+        tab_index => tab_index,
+    });
+}
+
+/// Index of the element to focus next, wrapping around at either end.
+///
+/// Starts at the first or last element when the current element is not in the list.
+fn next_tab_index(length: usize, current: Option<usize>, backwards: bool) -> Option<usize> {
+    if length == 0 {
+        return None;
+    }
+
+    Some(match (current, backwards) {
+        (Some(current), false) => (current + 1) % length,
+        (Some(current), true) => (current + length - 1) % length,
+        (None, false) => 0,
+        (None, true) => length - 1,
+    })
+}
+
+fn focus(element: &HtmlElement) {
+    if let Err(error) = element.focus() {
+        console::error_3(&"aria-hidden: cannot focus".into(), element, &error);
+    }
+}
+
+fn is_active(scope: &Rc<FocusScope>) -> bool {
+    FOCUS_SCOPES.with_borrow(|scopes| scopes.last().is_some_and(|last| Rc::ptr_eq(last, scope)))
+}
+
+fn remove_listeners(scope: &FocusScope) {
+    let Some((key_down, focus_in)) = scope.listeners.take() else {
+        return;
+    };
+    let Some(document) = scope
+        .targets
+        .first()
+        .and_then(|target| target.owner_document())
+    else {
+        return;
+    };
+
+    for (event_type, listener) in [
+        ("keydown", key_down.as_ref()),
+        ("focusin", focus_in.as_ref()),
+    ] {
+        if let Err(error) = document.remove_event_listener_with_callback_and_bool(
+            event_type,
+            listener.unchecked_ref(),
+            true,
+        ) {
+            console::error_2(
+                &"aria-hidden: cannot stop listening to focus".into(),
+                &error,
+            );
+        }
+    }
+}
+
+/// Removes the scope and its listeners. Leaked scopes are never released, so their listeners stay alive.
+fn release(scope: &Rc<FocusScope>) {
+    let index = FOCUS_SCOPES.with_borrow_mut(|scopes| {
+        let index = scopes.iter().position(|other| Rc::ptr_eq(other, scope))?;
+        scopes.remove(index);
+        Some(index)
+    });
+    let Some(index) = index else {
+        return;
+    };
+
+    remove_listeners(scope);
+
+    let previously_focused = scope.previously_focused.take();
+    let scope_above = FOCUS_SCOPES.with_borrow(|scopes| scopes.get(index).cloned());
+
+    match scope_above {
+        // Released out of order, so the scope above restores focus when it is released instead.
+        Some(scope_above) => {
+            scope_above.previously_focused.replace(previously_focused);
+        }
+        None => {
+            if let Some(previously_focused) = previously_focused
+                && previously_focused.is_connected()
+            {
+                focus(&previously_focused);
+            }
+        }
+    }
+}
+
+fn handle_key_down(scope: &Rc<FocusScope>, document: &Document, event: &KeyboardEvent) {
+    if event.key() != "Tab"
+        || event.alt_key()
+        || event.ctrl_key()
+        || event.meta_key()
+        || !is_active(scope)
+    {
+        return;
+    }
+
+    // Targets are not necessarily adjacent and positive tab indices may point outside, so the browser can't move
+    // focus on its own.
+    event.prevent_default();
+
+    let tabbable_elements = scope.tabbable_elements();
+    let current = document.active_element().and_then(|active_element| {
+        tabbable_elements
+            .iter()
+            .position(|element| **element == active_element)
+    });
+
+    if let Some(index) = next_tab_index(tabbable_elements.len(), current, event.shift_key()) {
+        focus(&tabbable_elements[index]);
+    }
+}
+
+fn handle_focus_in(scope: &Rc<FocusScope>, event: &FocusEvent) {
+    if !is_active(scope) {
+        return;
+    }
+
+    if let Some(target) = event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        && !scope.contains(&target)
+    {
+        scope.focus_first();
+    }
+}
+
+/// Keeps keyboard focus inside the given elements.
+///
+/// Moves focus to the first tabbable element if focus is not inside yet, moves Tab and Shift+Tab through the visible
+/// tabbable elements of all targets in sequential focus navigation order, wrapping around at either end, and pulls
+/// focus back when it moves outside. Use [`inert_others_with_focus_scope`] to also make everything else inert, as for
+/// a modal dialog.
+///
+/// Scopes stack like the locks of [`lock_depth`](crate::lock_depth): only the most recent scope contains focus.
+/// Releasing it restores focus to the element which was focused before, even when scopes are released out of order.
+///
+/// Parameters:
+/// * `targets`: Elements to keep focus in.
+///
+/// Return:
+/// Guard which releases the scope when released or dropped, or an error if there is nothing to contain focus in.
+pub fn focus_scope(targets: Vec<Element>) -> Result<Guard, AriaHiddenError> {
+    if targets.is_empty() {
+        return Err(AriaHiddenError::EmptyTargets);
+    }
+    if !targets.iter().any(|target| target.is_connected()) {
+        return Err(AriaHiddenError::DetachedTargets);
+    }
+    let document = targets
+        .first()
+        .and_then(|target| target.owner_document())
+        .ok_or(AriaHiddenError::DetachedTargets)?;
+
+    let scope = Rc::new(FocusScope {
+        targets,
+        previously_focused: RefCell::new(
+            document
+                .active_element()
+                .and_then(|element| element.dyn_into::<HtmlElement>().ok()),
+        ),
+        listeners: RefCell::new(None),
+    });
+    FOCUS_SCOPES.with_borrow_mut(|scopes| scopes.push(scope.clone()));
+
+    if !document
+        .active_element()
+        .is_some_and(|active_element| scope.contains(&active_element))
+    {
+        scope.focus_first();
+    }
+
+    let key_down = Closure::<dyn Fn(KeyboardEvent)>::new({
+        let scope = Rc::downgrade(&scope);
+        let document = document.clone();
+
+        move |event: KeyboardEvent| {
+            if let Some(scope) = scope.upgrade() {
+                handle_key_down(&scope, &document, &event);
+            }
+        }
+    });
+    let focus_in = Closure::<dyn Fn(FocusEvent)>::new({
+        let scope = Rc::downgrade(&scope);
+
+        move |event: FocusEvent| {
+            if let Some(scope) = scope.upgrade() {
+                handle_focus_in(&scope, &event);
+            }
+        }
+    });
+
+    for (event_type, listener) in [
+        ("keydown", key_down.as_ref()),
+        ("focusin", focus_in.as_ref()),
+    ] {
+        if let Err(error) = document.add_event_listener_with_callback_and_bool(
+            event_type,
+            listener.unchecked_ref(),
+            true,
+        ) {
+            console::error_2(&"aria-hidden: cannot listen to focus".into(), &error);
+        }
+    }
+    scope.listeners.replace(Some((key_down, focus_in)));
+
+    Ok(Guard::new(move || release(&scope)))
+}
+
+/// Makes everything except the targets inert and keeps keyboard focus inside them, as for a modal dialog.
+///
+/// Combines [`inert_others`] and [`focus_scope`] under one guard. Releasing it removes inert before restoring focus,
+/// so the previously focused element can receive focus again.
+///
+/// Parameters:
+/// * `original_target`: Elements to keep on the page and to keep focus in.
+/// * `parent_node`: Top element, defaults to document.body.
+/// * `marker_name`:  A special attribute to mark every node.
+/// * `options`: See [`SuppressOthersOptions`].
+///
+/// Return:
+/// Guard which restores the elements and focus when released or dropped, or an error if nothing can be suppressed.
+pub fn inert_others_with_focus_scope(
+    original_target: Vec<Element>,
+    parent_node: Option<HtmlElement>,
+    marker_name: Option<&str>,
+    options: SuppressOthersOptions,
+) -> Result<Guard, AriaHiddenError> {
+    let inert_guard = inert_others(original_target.clone(), parent_node, marker_name, options)?;
+    let focus_guard = focus_scope(original_target)?;
+
+    // Guards are released in reverse order.
+    Ok(Guard::join(vec![focus_guard, inert_guard]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Elements as `(tree position, tab index)`.
+    fn tab_order(mut elements: Vec<(u32, i32)>) -> Vec<u32> {
+        sort_tab_order(&mut elements, |a, b| a.0.cmp(&b.0), |element| element.1);
+        elements.into_iter().map(|element| element.0).collect()
+    }
+
+    #[test]
+    fn multiple_targets_in_tree_order() {
+        // Second target comes first in the document.
+        assert_eq!(
+            tab_order(vec![(5, 0), (6, 0), (1, 0), (2, 0)]),
+            [1, 2, 5, 6]
+        );
+    }
+
+    #[test]
+    fn nested_targets_without_duplicates() {
+        assert_eq!(
+            tab_order(vec![(1, 0), (2, 0), (3, 0), (2, 0), (3, 0)]),
+            [1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn positive_tab_index_first() {
+        assert_eq!(
+            tab_order(vec![(1, 0), (2, 2), (3, 0), (4, 1), (5, 2)]),
+            [4, 2, 5, 1, 3]
+        );
+    }
+
+    #[test]
+    fn wrap_around_forwards() {
+        assert_eq!(next_tab_index(3, Some(0), false), Some(1));
+        assert_eq!(next_tab_index(3, Some(2), false), Some(0));
+    }
+
+    #[test]
+    fn wrap_around_backwards() {
+        assert_eq!(next_tab_index(3, Some(2), true), Some(1));
+        assert_eq!(next_tab_index(3, Some(0), true), Some(2));
+    }
+
+    #[test]
+    fn focus_outside_tab_order() {
+        assert_eq!(next_tab_index(3, None, false), Some(0));
+        assert_eq!(next_tab_index(3, None, true), Some(2));
+        assert_eq!(next_tab_index(0, None, false), None);
+    }
+}
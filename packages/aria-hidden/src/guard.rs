@@ -0,0 +1,80 @@
+use std::rc::Rc;
+
+/// Restores the suppressed elements when released or dropped.
+///
+/// Returned by [`hide_others`](crate::hide_others), [`inert_others`](crate::inert_others),
+/// [`supress_others`](crate::supress_others), [`focus_scope`](crate::focus_scope) and
+/// [`inert_others_with_focus_scope`](crate::inert_others_with_focus_scope).
+#[must_use = "dropping the guard immediately restores the suppressed elements"]
+pub struct Guard {
+    undo: Option<Box<dyn FnOnce()>>,
+    /// Whether the elements were restored without the guard, e.g. by [`reset_all`](crate::reset_all).
+    is_released: Rc<dyn Fn() -> bool>,
+}
+
+impl Guard {
+    pub(crate) fn new(undo: impl FnOnce() + 'static) -> Self {
+        Guard {
+            undo: Some(Box::new(undo)),
+            is_released: Rc::new(|| false),
+        }
+    }
+
+    pub(crate) fn inactive() -> Self {
+        Guard {
+            undo: None,
+            is_released: Rc::new(|| false),
+        }
+    }
+
+    pub(crate) fn with_is_released(mut self, is_released: impl Fn() -> bool + 'static) -> Self {
+        self.is_released = Rc::new(is_released);
+        self
+    }
+
+    pub(crate) fn join(guards: Vec<Guard>) -> Self {
+        let is_released = guards
+            .iter()
+            .filter(|guard| guard.undo.is_some())
+            .map(|guard| guard.is_released.clone())
+            .collect::<Vec<_>>();
+
+        Guard::new(move || {
+            for mut guard in guards.into_iter().rev() {
+                guard.release();
+            }
+        })
+        .with_is_released(move || is_released.iter().all(|is_released| is_released()))
+    }
+
+    /// Whether the guard still has to restore the suppressed elements.
+    ///
+    /// Returns `false` after the guard is released or leaked, and after [`reset_all`](crate::reset_all) restored the
+    /// elements.
+    pub fn is_active(&self) -> bool {
+        self.undo.is_some() && !(self.is_released)()
+    }
+
+    /// Restore the suppressed elements.
+    ///
+    /// Calling this more than once, dropping the guard afterwards or calling it after
+    /// [`reset_all`](crate::reset_all) does nothing.
+    pub fn release(&mut self) {
+        if let Some(undo) = self.undo.take()
+            && !(self.is_released)()
+        {
+            undo();
+        }
+    }
+
+    /// Keep the elements suppressed forever, without restoring them on drop.
+    pub fn leak(mut self) {
+        self.undo = None;
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
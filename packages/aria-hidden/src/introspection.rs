@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use web_sys::{Document, Element, wasm_bindgen::JsCast};
+
+use crate::{REGISTRY, WALKS, dom::WebDom, release};
+
+/// Element which is currently suppressed.
+#[derive(Clone, Debug)]
+pub struct SuppressedElement {
+    pub element: Element,
+
+    /// Number of active locks suppressing the element, per control attribute.
+    pub counters: HashMap<String, u64>,
+
+    /// Number of active locks suppressing the element, per marker name.
+    pub markers: HashMap<String, u64>,
+
+    /// Values of the control attributes before the element was suppressed, which are restored on release.
+    pub original_values: HashMap<String, Option<String>>,
+}
+
+/// Elements which are currently suppressed.
+pub fn suppressed_elements() -> Vec<SuppressedElement> {
+    let mut suppressed_elements: Vec<SuppressedElement> = vec![];
+
+    REGISTRY.with_borrow(|registry| {
+        for (control_attribute, counter) in &registry.counter_map {
+            for (node, value) in counter.iter() {
+                // Only elements are suppressed.
+                let Some(element) = node.dyn_ref::<Element>() else {
+                    continue;
+                };
+
+                let index = match suppressed_elements
+                    .iter()
+                    .position(|suppressed_element| suppressed_element.element == *element)
+                {
+                    Some(index) => index,
+                    None => {
+                        suppressed_elements.push(SuppressedElement {
+                            element: element.clone(),
+                            counters: HashMap::new(),
+                            markers: registry
+                                .marker_map
+                                .iter()
+                                .filter_map(|(marker_name, marker_counter)| {
+                                    marker_counter
+                                        .get(&WebDom, node)
+                                        .map(|value| (marker_name.clone(), *value))
+                                })
+                                .collect(),
+                            original_values: HashMap::new(),
+                        });
+                        suppressed_elements.len() - 1
+                    }
+                };
+
+                let suppressed_element = &mut suppressed_elements[index];
+                suppressed_element
+                    .counters
+                    .insert(control_attribute.clone(), *value);
+                suppressed_element.original_values.insert(
+                    control_attribute.clone(),
+                    registry
+                        .uncontrolled_nodes
+                        .get(control_attribute)
+                        .and_then(|original_values| original_values.get(&WebDom, node).cloned())
+                        .flatten(),
+                );
+            }
+        }
+    });
+
+    suppressed_elements
+}
+
+/// Release every active lock suppressing elements of the document, including leaked guards.
+///
+/// Meant for test teardown and hot reload. Guards of released locks are no longer active and do nothing when released
+/// or dropped afterwards.
+pub fn reset_all(document: &Document) {
+    let walks = WALKS.with_borrow(|walks| {
+        walks
+            .iter()
+            .filter(|walk| walk.borrow().document.as_ref() == Some(document))
+            .cloned()
+            .collect::<Vec<_>>()
+    });
+
+    for walk in walks {
+        release(&walk);
+    }
+}
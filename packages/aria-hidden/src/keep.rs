@@ -0,0 +1,104 @@
+use web_sys::{Element, Node, console, wasm_bindgen::JsCast};
+
+use crate::{
+    dom::{Dom, WebDom},
+    options::KeepPredicate,
+    suppressor::ParentNode,
+};
+
+/// Rules for elements which are never suppressed, next to the targets.
+#[derive(Clone)]
+pub struct Keep {
+    selectors: Vec<String>,
+    predicate: Option<KeepPredicate>,
+    /// Whether descendants inside open shadow roots are kept too.
+    shadow_dom: bool,
+}
+
+impl Keep {
+    pub fn new(
+        keep_aria_live: bool,
+        selectors: &[String],
+        predicate: Option<KeepPredicate>,
+        shadow_dom: bool,
+    ) -> Self {
+        let mut all_selectors = vec![];
+        if keep_aria_live {
+            // We should not hide aria-live elements (https://github.com/theKashey/aria-hidden/issues/10).
+            all_selectors.push("[aria-live]".to_string());
+        }
+        all_selectors.extend(selectors.iter().cloned());
+
+        Keep {
+            selectors: all_selectors,
+            predicate,
+            shadow_dom,
+        }
+    }
+
+    /// Whether the element itself should be kept.
+    pub fn matches(&self, element: &Element) -> bool {
+        self.selectors.iter().any(|selector| {
+            element.matches(selector).unwrap_or_else(|error| {
+                console::error_3(
+                    &"aria-hidden: invalid selector".into(),
+                    &selector.into(),
+                    &error,
+                );
+                false
+            })
+        }) || self
+            .predicate
+            .as_ref()
+            .is_some_and(|predicate| predicate(element))
+    }
+
+    /// Descendants of the node which should be kept.
+    pub fn descendants(&self, node: &ParentNode) -> Vec<Element> {
+        let mut elements = vec![];
+        self.collect_descendants(node.as_node(), &mut elements);
+        elements
+    }
+
+    fn collect_descendants(&self, node: &Node, elements: &mut Vec<Element>) {
+        for selector in &self.selectors {
+            match WebDom.query_selector_all(node, selector) {
+                Ok(nodes) => {
+                    elements.extend(nodes.into_iter().filter_map(|node| node.dyn_into().ok()))
+                }
+                Err(error) => console::error_3(
+                    &"aria-hidden: invalid selector".into(),
+                    &selector.into(),
+                    &error,
+                ),
+            }
+        }
+
+        if self.predicate.is_none() && !self.shadow_dom {
+            return;
+        }
+        let Ok(nodes) = WebDom.query_selector_all(node, "*") else {
+            return;
+        };
+
+        if let Some(predicate) = &self.predicate {
+            elements.extend(
+                nodes
+                    .iter()
+                    .filter_map(|node| node.dyn_ref::<Element>())
+                    .filter(|element| predicate(element))
+                    .cloned(),
+            );
+        }
+
+        if self.shadow_dom {
+            // Selectors don't match across shadow boundaries, so every open shadow root is searched on its own.
+            for shadow_root in std::iter::once(node)
+                .chain(&nodes)
+                .filter_map(|node| WebDom.shadow_root(node))
+            {
+                self.collect_descendants(&shadow_root, elements);
+            }
+        }
+    }
+}
@@ -1,77 +1,147 @@
-// TODO: remove
-#![allow(dead_code, unused_variables)]
+mod documents;
+mod dom;
+mod error;
+mod focus_scope;
+mod guard;
+mod introspection;
+mod keep;
+#[cfg(test)]
+mod memory;
+mod options;
+mod suppressor;
+mod util;
+mod walk;
+
+pub use error::*;
+pub use focus_scope::*;
+pub use guard::*;
+pub use introspection::*;
+pub use options::*;
+pub use suppressor::*;
 
 use std::{
-    cell::RefCell,
-    collections::{HashMap, HashSet},
-    sync::atomic::AtomicU64,
+    cell::{Cell, RefCell},
+    rc::Rc,
 };
 
-use web_sys::{Element, HtmlElement, Node, NodeList, ShadowRoot, wasm_bindgen::JsCast};
+use web_sys::{
+    Document, Element, HtmlElement, MutationObserver, MutationObserverInit, MutationRecord, Node,
+    console,
+    js_sys::{Array, Object, Reflect, global},
+    wasm_bindgen::{JsCast, JsValue, closure::Closure},
+};
+
+use crate::{
+    documents::frame_elements,
+    dom::WebDom,
+    keep::Keep,
+    util::node_list_to_elements,
+    walk::{Registry, Walker, correct_targets},
+};
 
 // Element is not Send and WebAssembly is single threaded, so this should be fine for now.
 thread_local! {
-    static COUNTER_MAP: RefCell<HashMap<Element, u64>> = RefCell::new(HashMap::new());
-    static UNCONTROLLED_NODES: RefCell<HashMap<Element, bool>> = RefCell::new(HashMap::new());
-    static MARKER_MAP: RefCell<HashMap<String, HashMap<Element, u64>>> = RefCell::new(HashMap::new());
-    static LOCK_COUNT: AtomicU64 = const { AtomicU64::new(0) };
+    static REGISTRY: RefCell<Registry<WebDom>> = RefCell::new(Registry::new());
+    static WALKS: RefCell<Vec<Rc<RefCell<Walk>>>> = const { RefCell::new(Vec::new()) };
+    static SUPPORTS_INERT: Cell<Option<bool>> = const { Cell::new(None) };
+    static SUPPORTS_INERT_OVERRIDE: Cell<Option<bool>> = const { Cell::new(None) };
 }
 
-type Undo = Box<dyn Fn()>;
-
-fn get_default_parent(original_target: &[Element]) -> Option<HtmlElement> {
+fn get_default_parent(original_target: &[Element]) -> Result<HtmlElement, AriaHiddenError> {
     original_target
         .first()
-        .expect("At least one element is required.")
+        .ok_or(AriaHiddenError::EmptyTargets)?
         .owner_document()
         .and_then(|document| document.body())
+        .ok_or(AriaHiddenError::MissingBody)
 }
 
-#[derive(Clone, Debug)]
-enum NodeOrShadowRoot {
-    Node(Node),
-    ShadowRoot(ShadowRoot),
+/// Implements <https://dom.spec.whatwg.org/#valid-attribute-local-name>.
+fn is_valid_attribute_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.chars().any(|character| {
+            character.is_ascii_whitespace() || matches!(character, '\0' | '/' | '=' | '>')
+        })
 }
 
-impl From<Node> for NodeOrShadowRoot {
-    fn from(value: Node) -> Self {
-        NodeOrShadowRoot::Node(value)
-    }
-}
+type MutationCallback = Closure<dyn FnMut(Array, MutationObserver)>;
 
-impl From<ShadowRoot> for NodeOrShadowRoot {
-    fn from(value: ShadowRoot) -> Self {
-        NodeOrShadowRoot::ShadowRoot(value)
-    }
+/// State of a single [`apply_attribute_to_others`] call, shared with the guard and the live mode observer.
+struct Walk {
+    document: Option<Document>,
+    walker: Walker<WebDom>,
+    keep: Keep,
+    observer: Option<(MutationObserver, MutationCallback)>,
+    released: bool,
 }
 
-fn unwrap_host(node: NodeOrShadowRoot) -> Option<Element> {
-    match node {
-        NodeOrShadowRoot::Node(node) => match node.parent_node() {
-            None => None,
-            Some(parent_node) => unwrap_host(NodeOrShadowRoot::Node(parent_node)),
-        },
-        NodeOrShadowRoot::ShadowRoot(shadow_root) => Some(shadow_root.host()),
-    }
-}
+/// Suppresses elements inserted into any of the walked nodes, until the observer is disconnected.
+fn observe(walk: &Rc<RefCell<Walk>>) -> Option<(MutationObserver, MutationCallback)> {
+    let callback = MutationCallback::new({
+        let walk = walk.clone();
 
-fn correct_targets(parent: HtmlElement, targets: Vec<Element>) -> Vec<Element> {
-    targets
-        .into_iter()
-        .filter_map(|target| {
-            if parent.contains(Some(&target)) {
-                return Some(target);
-            }
+        move |records: Array, observer: MutationObserver| {
+            let mut walk = walk.borrow_mut();
+            let Walk { walker, keep, .. } = &mut *walk;
+            let walked_nodes = walker.walked_nodes.len();
+
+            for record in records.iter() {
+                let record = record.unchecked_into::<MutationRecord>();
+
+                for node in node_list_to_elements(record.added_nodes()) {
+                    if walker.elements_to_keep.contains(&walker.dom, node.as_ref())
+                        || keep.matches(&node)
+                    {
+                        continue;
+                    }
 
-            if let Some(corrected_target) = unwrap_host(NodeOrShadowRoot::Node((*target).clone()))
-                && parent.contains(Some(&corrected_target))
-            {
-                return Some(corrected_target);
+                    let kept_descendants = keep.descendants(&node.clone().into());
+
+                    REGISTRY.with_borrow_mut(|registry| {
+                        if kept_descendants.is_empty() {
+                            walker.suppress(registry, node.into());
+                        } else {
+                            for kept_descendant in kept_descendants {
+                                let kept_descendant = Node::from(kept_descendant);
+                                walker
+                                    .elements_to_stop
+                                    .insert(&walker.dom, kept_descendant.clone());
+                                walker.keep(Some(kept_descendant));
+                            }
+                            walker.deep(registry, &node.into());
+                        }
+                    });
+                }
             }
 
-            None
-        })
-        .collect()
+            // Inserted nodes with kept descendants are walked, so insertions into them are suppressed as well.
+            observe_nodes(&observer, &walker.walked_nodes[walked_nodes..]);
+        }
+    });
+
+    let observer = match MutationObserver::new(callback.as_ref().unchecked_ref()) {
+        Ok(observer) => observer,
+        Err(error) => {
+            console::error_2(&"aria-hidden: cannot observe mutations".into(), &error);
+            return None;
+        }
+    };
+
+    observe_nodes(&observer, &walk.borrow().walker.walked_nodes);
+
+    Some((observer, callback))
+}
+
+/// Observes insertions into the nodes.
+fn observe_nodes(observer: &MutationObserver, nodes: &[Node]) {
+    let init = MutationObserverInit::new();
+    init.set_child_list(true);
+
+    for node in nodes {
+        if let Err(error) = observer.observe_with_options(node, &init) {
+            console::error_3(&"aria-hidden: cannot observe".into(), node, &error);
+        }
+    }
 }
 
 /// Marks everything except given nodes with an attribute.
@@ -81,41 +151,168 @@ fn correct_targets(parent: HtmlElement, targets: Vec<Element>) -> Vec<Element> {
 /// * `parent_node`: Top element, defaults to document.body.
 /// * `marker_name`:  A special attribute to mark every node.
 /// * `control_attribute`: Attribute to control.
+/// * `options`: See [`SuppressOthersOptions`].
 ///
 /// Return:
-/// Undo command.
+/// Guard which restores the elements when released or dropped, or an error if nothing can be suppressed.
 fn apply_attribute_to_others(
     original_target: Vec<Element>,
-    parent_node: HtmlElement,
+    parent_node: ParentNode,
     marker_name: &str,
     control_attribute: &str,
-) -> Undo {
-    let targets = correct_targets(parent_node, original_target);
+    options: &SuppressOthersOptions,
+) -> Result<Guard, AriaHiddenError> {
+    if original_target.is_empty() {
+        return Err(AriaHiddenError::EmptyTargets);
+    }
+    if !is_valid_attribute_name(marker_name) {
+        return Err(AriaHiddenError::InvalidMarkerName(marker_name.into()));
+    }
+    if correct_targets(
+        &WebDom,
+        parent_node.as_node(),
+        original_target.iter().cloned().map(Node::from).collect(),
+        options.shadow_dom.unwrap_or(false),
+    )
+    .is_empty()
+    {
+        return Err(AriaHiddenError::DetachedTargets);
+    }
+
+    let document = parent_node.owner_document();
+    let mut guards = vec![walk_others(
+        original_target.clone(),
+        parent_node,
+        marker_name,
+        control_attribute,
+        options,
+    )];
+
+    if let Some(document) = document {
+        // Frame elements are kept, so the targets inside them stay accessible.
+        let frame_elements = frame_elements(&document);
 
-    MARKER_MAP.with_borrow_mut(|marker_map| {
-        if !marker_map.contains_key(marker_name) {
-            marker_map.insert(marker_name.into(), HashMap::new());
+        let mut other_documents = vec![];
+        if options.parent_documents.unwrap_or(false) {
+            other_documents.extend(
+                frame_elements
+                    .iter()
+                    .filter_map(|frame_element| frame_element.owner_document()),
+            );
         }
+        for other_document in &options.documents {
+            if *other_document != document && !other_documents.contains(other_document) {
+                other_documents.push(other_document.clone());
+            }
+        }
+
+        for other_document in other_documents {
+            let Some(body) = other_document.body() else {
+                continue;
+            };
+
+            let targets = original_target
+                .iter()
+                .chain(frame_elements.iter())
+                .filter(|target| target.owner_document().as_ref() == Some(&other_document))
+                .cloned()
+                .collect();
+
+            guards.push(walk_others(
+                targets,
+                body.into(),
+                marker_name,
+                control_attribute,
+                options,
+            ));
+        }
+    }
+
+    Ok(Guard::join(guards))
+}
+
+/// Marks everything except given nodes with an attribute, without validating the targets.
+fn walk_others(
+    original_target: Vec<Element>,
+    parent_node: ParentNode,
+    marker_name: &str,
+    control_attribute: &str,
+    options: &SuppressOthersOptions,
+) -> Guard {
+    let keep = Keep::new(
+        options.keep_aria_live.unwrap_or(true),
+        &options.keep_selectors,
+        options.keep.clone(),
+        options.shadow_dom.unwrap_or(false),
+    );
+
+    let targets = original_target
+        .into_iter()
+        .chain(keep.descendants(&parent_node))
+        .map(Node::from)
+        .collect();
+
+    let walker = REGISTRY.with_borrow_mut(|registry| {
+        Walker::lock(
+            WebDom,
+            registry,
+            parent_node.as_node(),
+            targets,
+            marker_name,
+            control_attribute,
+            options.shadow_dom.unwrap_or(false),
+        )
     });
 
-    let hidden_nodes: Vec<Element> = vec![];
-    // TODO: insert doesn't exist, because Node doesn't implement Hash
-    let elements_to_keep: HashSet<Node> = HashSet::new();
-    let elements_to_stop: HashSet<Node> = HashSet::new();
+    let walk = Rc::new(RefCell::new(Walk {
+        document: parent_node.owner_document(),
+        walker,
+        keep,
+        observer: None,
+        released: false,
+    }));
+    if options.live.unwrap_or(false) {
+        let observer = observe(&walk);
+        walk.borrow_mut().observer = observer;
+    }
+    WALKS.with_borrow_mut(|walks| walks.push(walk.clone()));
 
-    // TODO
+    Guard::new({
+        let walk = walk.clone();
 
-    Box::new(|| {})
+        move || release(&walk)
+    })
+    .with_is_released(move || walk.borrow().released)
 }
 
-fn node_list_to_elements(node_list: NodeList) -> Vec<Element> {
-    let mut elements: Vec<Element> = vec![];
-    for n in 0..node_list.length() {
-        if let Some(element) = node_list.item(n).and_then(|node| node.dyn_into().ok()) {
-            elements.push(element);
+/// Restores everything suppressed by the walk and releases its lock. Does nothing if already released.
+fn release(walk: &Rc<RefCell<Walk>>) {
+    {
+        let mut walk = walk.borrow_mut();
+        if walk.released {
+            return;
         }
+        walk.released = true;
+
+        // Dropping the callback also breaks its reference cycle with the walk.
+        if let Some((observer, _callback)) = walk.observer.take() {
+            observer.disconnect();
+        }
+
+        REGISTRY.with_borrow_mut(|registry| walk.walker.unlock(registry));
     }
-    elements
+
+    WALKS.with_borrow_mut(|walks| walks.retain(|other| !Rc::ptr_eq(other, walk)));
+}
+
+/// Number of active locks, i.e. guards which have not been released yet.
+///
+/// Locks stack like in the npm package: every call to [`hide_others`], [`inert_others`] or [`supress_others`]
+/// adds a lock and increments a counter on each element it suppresses. An element is only restored once every
+/// lock suppressing it has been released, in any order. Releasing the last lock resets all bookkeeping.
+/// Suppressing content in multiple documents adds a lock per document.
+pub fn lock_depth() -> u64 {
+    REGISTRY.with_borrow(|registry| registry.lock_count)
 }
 
 /// Marks everything except given nodes as aria-hidden.
@@ -124,31 +321,28 @@ fn node_list_to_elements(node_list: NodeList) -> Vec<Element> {
 /// * `original_target`: Elements to keep on the page.
 /// * `parent_node`: Top element, defaults to document.body.
 /// * `marker_name`:  A special attribute to mark every node.
+/// * `options`: See [`SuppressOthersOptions`].
 ///
 /// Return:
-/// Undo command.
+/// Guard which restores the elements when released or dropped, or an error if nothing can be suppressed.
 pub fn hide_others(
     original_target: Vec<Element>,
     parent_node: Option<HtmlElement>,
     marker_name: Option<&str>,
-) -> Undo {
-    let marker_name = marker_name.unwrap_or("data-aria-hidden");
-
-    let mut targets = original_target.clone();
-    let active_parent_node = parent_node.or(get_default_parent(&original_target));
-
-    if let Some(active_parent_node) = active_parent_node {
-        // We should not hide aria-live elements (https://github.com/theKashey/aria-hidden/issues/10).
-        targets.extend(node_list_to_elements(
-            active_parent_node
-                .query_selector_all("[aria-live]")
-                .expect("Element should be queried."),
-        ));
-
-        apply_attribute_to_others(targets, active_parent_node, marker_name, "aria-hidden")
-    } else {
-        Box::new(|| {})
+    options: SuppressOthersOptions,
+) -> Result<Guard, AriaHiddenError> {
+    let mut suppressor = Suppressor::new()
+        .targets(original_target)
+        .strategy(Strategy::Hide)
+        .options(options);
+    if let Some(parent_node) = parent_node {
+        suppressor = suppressor.root(parent_node);
+    }
+    if let Some(marker_name) = marker_name {
+        suppressor = suppressor.marker(marker_name);
     }
+
+    suppressor.apply()
 }
 
 /// Marks everything except given nodes as inert.
@@ -157,28 +351,63 @@ pub fn hide_others(
 /// * `original_target`: Elements to keep on the page.
 /// * `parent_node`: Top element, defaults to document.body.
 /// * `marker_name`:  A special attribute to mark every node.
+/// * `options`: See [`SuppressOthersOptions`].
 ///
 /// Return:
-/// Undo command.
+/// Guard which restores the elements when released or dropped, or an error if nothing can be suppressed.
 pub fn inert_others(
     original_target: Vec<Element>,
     parent_node: Option<HtmlElement>,
     marker_name: Option<&str>,
-) -> Undo {
-    let marker_name = marker_name.unwrap_or("data-inert-ed");
+    options: SuppressOthersOptions,
+) -> Result<Guard, AriaHiddenError> {
+    let mut suppressor = Suppressor::new()
+        .targets(original_target)
+        .strategy(Strategy::Inert)
+        .options(options);
+    if let Some(parent_node) = parent_node {
+        suppressor = suppressor.root(parent_node);
+    }
+    if let Some(marker_name) = marker_name {
+        suppressor = suppressor.marker(marker_name);
+    }
 
-    let active_parent_node = parent_node.or(get_default_parent(&original_target));
+    suppressor.apply()
+}
 
-    if let Some(active_parent_node) = active_parent_node {
-        apply_attribute_to_others(original_target, active_parent_node, marker_name, "inert")
-    } else {
-        Box::new(|| {})
-    }
+fn detect_inert() -> bool {
+    Reflect::get(&global(), &"HTMLElement".into())
+        .ok()
+        .filter(|html_element| !html_element.is_undefined())
+        .and_then(|html_element| Reflect::get(&html_element, &"prototype".into()).ok())
+        .and_then(|prototype| prototype.dyn_into::<Object>().ok())
+        .is_some_and(|prototype| Object::has_own(&prototype, &JsValue::from_str("inert")))
 }
 
 /// Whether the current browser supports inert.
+///
+/// Detects `HTMLElement.prototype.inert` once per thread, unless overridden with [`override_supports_inert`].
 pub fn supports_inert() -> bool {
-    todo!()
+    if let Some(supports_inert) = SUPPORTS_INERT_OVERRIDE.get() {
+        return supports_inert;
+    }
+
+    match SUPPORTS_INERT.get() {
+        Some(supports_inert) => supports_inert,
+        None => {
+            let supports_inert = detect_inert();
+            SUPPORTS_INERT.set(Some(supports_inert));
+            supports_inert
+        }
+    }
+}
+
+/// Override the result of [`supports_inert`] for the current thread.
+///
+/// Useful for tests and for environments where inert is detected but not reliable, such as older embedded WebViews.
+/// Pass `Some(false)` to force hiding, `Some(true)` to force inert and `None` to restore feature detection.
+pub fn override_supports_inert(supports_inert: Option<bool>) {
+    SUPPORTS_INERT_OVERRIDE.set(supports_inert);
 }
 
 /// Automatic function to "suppress" DOM elements - _hide_ or _inert_ in the best possible way.
@@ -187,17 +416,26 @@ pub fn supports_inert() -> bool {
 /// * `original_target`: Elements to keep on the page.
 /// * `parent_node`: Top element, defaults to document.body.
 /// * `marker_name`:  A special attribute to mark every node.
+/// * `options`: See [`SuppressOthersOptions`].
 ///
 /// Return:
-/// Undo command.
+/// Guard which restores the elements when released or dropped, or an error if nothing can be suppressed.
 pub fn supress_others(
     original_target: Vec<Element>,
     parent_node: Option<HtmlElement>,
     marker_name: Option<&str>,
-) -> Undo {
-    if supports_inert() {
-        inert_others(original_target, parent_node, marker_name)
-    } else {
-        hide_others(original_target, parent_node, marker_name)
+    options: SuppressOthersOptions,
+) -> Result<Guard, AriaHiddenError> {
+    let mut suppressor = Suppressor::new()
+        .targets(original_target)
+        .strategy(Strategy::Auto)
+        .options(options);
+    if let Some(parent_node) = parent_node {
+        suppressor = suppressor.root(parent_node);
     }
+    if let Some(marker_name) = marker_name {
+        suppressor = suppressor.marker(marker_name);
+    }
+
+    suppressor.apply()
 }
@@ -0,0 +1,205 @@
+use std::{cell::RefCell, convert::Infallible, rc::Rc};
+
+use crate::dom::Dom;
+
+/// Node of a [`MemoryDom`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MemoryNode(usize);
+
+#[derive(Default)]
+struct NodeData {
+    /// `None` for shadow roots.
+    local_name: Option<String>,
+    attributes: Vec<(String, String)>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    host: Option<usize>,
+    shadow_root: Option<usize>,
+}
+
+/// [`Dom`] implementation without a browser, supporting simple selectors only.
+///
+/// Only compiled for the tests of this crate.
+#[derive(Clone, Default)]
+pub struct MemoryDom(Rc<RefCell<Vec<NodeData>>>);
+
+impl MemoryDom {
+    pub fn new() -> Self {
+        MemoryDom::default()
+    }
+
+    fn push(&self, data: NodeData) -> MemoryNode {
+        let mut nodes = self.0.borrow_mut();
+        nodes.push(data);
+        MemoryNode(nodes.len() - 1)
+    }
+
+    /// Creates an element without a parent.
+    pub fn create_element(&self, local_name: &str) -> MemoryNode {
+        self.push(NodeData {
+            local_name: Some(local_name.into()),
+            ..NodeData::default()
+        })
+    }
+
+    /// Creates an element and appends it to the parent.
+    pub fn append_element(&self, parent: MemoryNode, local_name: &str) -> MemoryNode {
+        let element = self.create_element(local_name);
+        self.append_child(parent, element);
+        element
+    }
+
+    pub fn append_child(&self, parent: MemoryNode, child: MemoryNode) {
+        let mut nodes = self.0.borrow_mut();
+        nodes[child.0].parent = Some(parent.0);
+        nodes[parent.0].children.push(child.0);
+    }
+
+    pub fn attach_shadow(&self, host: MemoryNode) -> MemoryNode {
+        let shadow_root = self.push(NodeData {
+            host: Some(host.0),
+            ..NodeData::default()
+        });
+        self.0.borrow_mut()[host.0].shadow_root = Some(shadow_root.0);
+        shadow_root
+    }
+
+    /// Inclusive descendants of the node in tree order, without crossing shadow boundaries.
+    fn inclusive_descendants(&self, node: MemoryNode) -> Vec<MemoryNode> {
+        let mut descendants = vec![node];
+        for child in self.children(&node) {
+            descendants.extend(self.inclusive_descendants(child));
+        }
+        descendants
+    }
+
+    fn matches_compound(&self, node: MemoryNode, selector: &str) -> bool {
+        let nodes = self.0.borrow();
+        let Some(local_name) = &nodes[node.0].local_name else {
+            return false;
+        };
+
+        let (type_selector, mut rest) = match selector.find(['[', '#']) {
+            Some(index) => selector.split_at(index),
+            None => (selector, ""),
+        };
+        if !type_selector.is_empty() && type_selector != "*" && type_selector != local_name {
+            return false;
+        }
+
+        while !rest.is_empty() {
+            if let Some(id) = rest.strip_prefix('#') {
+                let end = id.find(['[', '#']).unwrap_or(id.len());
+                if self.get_attribute(&node, "id").as_deref() != Some(&id[..end]) {
+                    return false;
+                }
+                rest = &id[end..];
+            } else if let Some(attribute) = rest.strip_prefix('[') {
+                let Some(end) = attribute.find(']') else {
+                    return false;
+                };
+                let matched = match attribute[..end].split_once('=') {
+                    Some((name, value)) => {
+                        self.get_attribute(&node, name.trim()).as_deref()
+                            == Some(value.trim().trim_matches(['"', '\'']))
+                    }
+                    None => self.get_attribute(&node, attribute[..end].trim()).is_some(),
+                };
+                if !matched {
+                    return false;
+                }
+                rest = &attribute[end + 1..];
+            } else {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl Dom for MemoryDom {
+    type Node = MemoryNode;
+    type Key = usize;
+    type Error = Infallible;
+
+    fn key(&self, node: &MemoryNode) -> usize {
+        node.0
+    }
+
+    fn parent(&self, node: &MemoryNode) -> Option<MemoryNode> {
+        self.0.borrow()[node.0].parent.map(MemoryNode)
+    }
+
+    fn children(&self, node: &MemoryNode) -> Vec<MemoryNode> {
+        self.0.borrow()[node.0]
+            .children
+            .iter()
+            .copied()
+            .map(MemoryNode)
+            .collect()
+    }
+
+    fn contains(&self, node: &MemoryNode, other: &MemoryNode) -> bool {
+        let mut current = Some(*other);
+        while let Some(ancestor) = current {
+            if ancestor == *node {
+                return true;
+            }
+            current = self.parent(&ancestor);
+        }
+        false
+    }
+
+    fn get_attribute(&self, node: &MemoryNode, name: &str) -> Option<String> {
+        self.0.borrow()[node.0]
+            .attributes
+            .iter()
+            .find_map(|(key, value)| (key == name).then(|| value.clone()))
+    }
+
+    fn set_attribute(&self, node: &MemoryNode, name: &str, value: &str) -> Result<(), Infallible> {
+        let attributes = &mut self.0.borrow_mut()[node.0].attributes;
+        match attributes.iter_mut().find(|(key, _)| key == name) {
+            Some((_, existing)) => *existing = value.into(),
+            None => attributes.push((name.into(), value.into())),
+        }
+        Ok(())
+    }
+
+    fn remove_attribute(&self, node: &MemoryNode, name: &str) -> Result<(), Infallible> {
+        self.0.borrow_mut()[node.0]
+            .attributes
+            .retain(|(key, _)| key != name);
+        Ok(())
+    }
+
+    fn query_selector_all(
+        &self,
+        node: &MemoryNode,
+        selectors: &str,
+    ) -> Result<Vec<MemoryNode>, Infallible> {
+        Ok(self
+            .inclusive_descendants(*node)
+            .into_iter()
+            .skip(1)
+            .filter(|descendant| {
+                selectors
+                    .split(',')
+                    .any(|selector| self.matches_compound(*descendant, selector.trim()))
+            })
+            .collect())
+    }
+
+    fn shadow_host(&self, node: &MemoryNode) -> Option<MemoryNode> {
+        self.0.borrow()[node.0].host.map(MemoryNode)
+    }
+
+    fn shadow_root(&self, node: &MemoryNode) -> Option<MemoryNode> {
+        self.0.borrow()[node.0].shadow_root.map(MemoryNode)
+    }
+
+    fn report_error(&self, _message: &str, _node: &MemoryNode, error: Infallible) {
+        match error {}
+    }
+}
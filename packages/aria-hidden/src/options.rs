@@ -0,0 +1,69 @@
+use std::{
+    fmt::{Debug, Formatter},
+    rc::Rc,
+};
+
+use web_sys::{Document, Element};
+
+/// Returns `true` for elements which should never be suppressed.
+pub type KeepPredicate = Rc<dyn Fn(&Element) -> bool>;
+
+/// Options for [`hide_others`](crate::hide_others), [`inert_others`](crate::inert_others) and
+/// [`supress_others`](crate::supress_others).
+#[derive(Clone, Default)]
+pub struct SuppressOthersOptions {
+    /// Set to `false` to also suppress `aria-live` regions.
+    ///
+    /// Defaults to `true`.
+    pub keep_aria_live: Option<bool>,
+
+    /// CSS selectors of additional elements to keep, e.g. `[data-keep-visible]` or `[role=status]`.
+    pub keep_selectors: Vec<String>,
+
+    /// Predicate for additional elements to keep.
+    pub keep: Option<KeepPredicate>,
+
+    /// Set to `true` to walk into open shadow roots on the way to the targets, suppressing siblings in both the light
+    /// and the shadow tree. Otherwise a target inside a shadow root is replaced by its host.
+    ///
+    /// Defaults to `false`.
+    pub shadow_dom: Option<bool>,
+
+    /// Set to `true` to also suppress elements inserted next to the suppressed ones while the guard is active, such as
+    /// toasts or portals. Uses a `MutationObserver`, which is disconnected when the guard is released.
+    ///
+    /// Defaults to `false`.
+    pub live: Option<bool>,
+
+    /// Set to `false` to ignore modal dialogs in the top layer, i.e. `<dialog>` elements opened with `showModal()`.
+    /// The browser already makes everything outside them inert, so by default nothing is suppressed for a modal
+    /// target, and only the content of the modal is suppressed for targets inside it.
+    ///
+    /// Defaults to `true`.
+    pub native_modal: Option<bool>,
+
+    /// Set to `true` to also suppress content of the parent documents when the targets are inside same-origin iframes.
+    /// The iframe elements themselves are kept.
+    ///
+    /// Defaults to `false`.
+    pub parent_documents: Option<bool>,
+
+    /// Other documents to suppress content in, e.g. of same-origin iframes embedded in the page.
+    /// Targets and iframe elements containing the targets in these documents are kept.
+    pub documents: Vec<Document>,
+}
+
+impl Debug for SuppressOthersOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // The keep predicate is a closure, which can't be formatted.
+        f.debug_struct("SuppressOthersOptions")
+            .field("keep_aria_live", &self.keep_aria_live)
+            .field("keep_selectors", &self.keep_selectors)
+            .field("shadow_dom", &self.shadow_dom)
+            .field("live", &self.live)
+            .field("native_modal", &self.native_modal)
+            .field("parent_documents", &self.parent_documents)
+            .field("documents", &self.documents)
+            .finish_non_exhaustive()
+    }
+}
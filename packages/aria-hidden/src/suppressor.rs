@@ -0,0 +1,217 @@
+use web_sys::{
+    Document, DocumentFragment, Element, HtmlElement, Node, NodeList, ShadowRoot, SvgElement,
+};
+
+use crate::{
+    AriaHiddenError, Guard, SuppressOthersOptions, apply_attribute_to_others, get_default_parent,
+    supports_inert, util::node_list_to_elements,
+};
+
+/// Node under which elements are suppressed.
+#[derive(Clone, Debug)]
+pub enum ParentNode {
+    Element(Element),
+    DocumentFragment(DocumentFragment),
+}
+
+impl ParentNode {
+    pub(crate) fn as_node(&self) -> &Node {
+        match self {
+            ParentNode::Element(element) => element,
+            ParentNode::DocumentFragment(document_fragment) => document_fragment,
+        }
+    }
+
+    pub(crate) fn owner_document(&self) -> Option<Document> {
+        self.as_node().owner_document()
+    }
+}
+
+impl From<Element> for ParentNode {
+    fn from(value: Element) -> Self {
+        ParentNode::Element(value)
+    }
+}
+
+impl From<HtmlElement> for ParentNode {
+    fn from(value: HtmlElement) -> Self {
+        ParentNode::Element(value.into())
+    }
+}
+
+impl From<SvgElement> for ParentNode {
+    fn from(value: SvgElement) -> Self {
+        ParentNode::Element(value.into())
+    }
+}
+
+impl From<DocumentFragment> for ParentNode {
+    fn from(value: DocumentFragment) -> Self {
+        ParentNode::DocumentFragment(value)
+    }
+}
+
+impl From<ShadowRoot> for ParentNode {
+    fn from(value: ShadowRoot) -> Self {
+        ParentNode::DocumentFragment(value.into())
+    }
+}
+
+/// How elements are suppressed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Strategy {
+    /// Mark elements with `aria-hidden`, see [`hide_others`](crate::hide_others).
+    Hide,
+    /// Mark elements with `inert`, see [`inert_others`](crate::inert_others).
+    Inert,
+    /// Use inert if supported, hide otherwise, see [`supress_others`](crate::supress_others).
+    #[default]
+    Auto,
+}
+
+/// How elements will be suppressed, see [`Suppressor::decision`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StrategyDecision {
+    /// Mark elements with `aria-hidden`.
+    Hide,
+    /// Mark elements with `inert`.
+    Inert,
+    /// A target is a modal dialog in the top layer, i.e. a `<dialog>` opened with `showModal()`.
+    /// The browser already makes everything else inert, so nothing is suppressed.
+    NativeModal,
+}
+
+/// Builder to suppress everything except the targets.
+///
+/// [`hide_others`](crate::hide_others), [`inert_others`](crate::inert_others) and
+/// [`supress_others`](crate::supress_others) are shorthands for this builder.
+#[derive(Clone, Default)]
+pub struct Suppressor {
+    targets: Vec<Element>,
+    root: Option<ParentNode>,
+    marker: Option<String>,
+    strategy: Strategy,
+    options: SuppressOthersOptions,
+}
+
+impl Suppressor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Elements to keep on the page.
+    pub fn targets<I>(mut self, targets: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<Element>,
+    {
+        self.targets
+            .extend(targets.into_iter().map(|target| target.as_ref().clone()));
+        self
+    }
+
+    /// Elements in the node list to keep on the page.
+    pub fn targets_from_node_list(mut self, node_list: NodeList) -> Self {
+        self.targets.extend(node_list_to_elements(node_list));
+        self
+    }
+
+    /// Top node, defaults to the body of the document of the first target.
+    pub fn root(mut self, root: impl Into<ParentNode>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    /// A special attribute to mark every node, defaults to `data-aria-hidden` or `data-inert-ed`.
+    pub fn marker(mut self, marker: impl Into<String>) -> Self {
+        self.marker = Some(marker.into());
+        self
+    }
+
+    /// How to suppress elements, defaults to [`Strategy::Auto`].
+    pub fn strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// See [`SuppressOthersOptions`].
+    pub fn options(mut self, options: SuppressOthersOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Decide how [`Suppressor::apply`] will suppress elements, resolving [`Strategy::Auto`] and native modality.
+    pub fn decision(&self) -> StrategyDecision {
+        if self.options.native_modal.unwrap_or(true) && self.targets.iter().any(is_modal) {
+            return StrategyDecision::NativeModal;
+        }
+
+        let inert = match self.strategy {
+            Strategy::Hide => false,
+            Strategy::Inert => true,
+            Strategy::Auto => supports_inert(),
+        };
+        if inert {
+            StrategyDecision::Inert
+        } else {
+            StrategyDecision::Hide
+        }
+    }
+
+    /// Suppress everything except the targets.
+    ///
+    /// Return:
+    /// Guard which restores the elements when released or dropped, or an error if nothing can be suppressed.
+    pub fn apply(self) -> Result<Guard, AriaHiddenError> {
+        if self.targets.is_empty() {
+            return Err(AriaHiddenError::EmptyTargets);
+        }
+
+        let (default_marker, control_attribute) = match self.decision() {
+            StrategyDecision::Hide => ("data-aria-hidden", "aria-hidden"),
+            StrategyDecision::Inert => ("data-inert-ed", "inert"),
+            StrategyDecision::NativeModal => return Ok(Guard::inactive()),
+        };
+
+        let root = match self.root {
+            Some(root) => root,
+            None => {
+                // Content outside a modal ancestor is already inert, so only its content has to be suppressed.
+                let modal_ancestor = self
+                    .options
+                    .native_modal
+                    .unwrap_or(true)
+                    .then(|| self.targets.iter().find_map(modal_ancestor))
+                    .flatten();
+
+                match modal_ancestor {
+                    Some(modal_ancestor) => modal_ancestor.into(),
+                    None => get_default_parent(&self.targets)?.into(),
+                }
+            }
+        };
+
+        apply_attribute_to_others(
+            self.targets,
+            root,
+            self.marker.as_deref().unwrap_or(default_marker),
+            control_attribute,
+            &self.options,
+        )
+    }
+}
+
+/// Modal dialogs in the top layer, i.e. `<dialog>` elements opened with `showModal()`.
+///
+/// `:modal` alone also matches fullscreen elements, which don't make the rest of the page inert.
+const MODAL_DIALOG_SELECTOR: &str = "dialog:modal";
+
+/// Whether the element is a modal dialog in the top layer.
+fn is_modal(element: &Element) -> bool {
+    // Browsers without `:modal` support throw, they don't support modal dialogs either.
+    element.matches(MODAL_DIALOG_SELECTOR).unwrap_or(false)
+}
+
+fn modal_ancestor(element: &Element) -> Option<Element> {
+    element.closest(MODAL_DIALOG_SELECTOR).ok().flatten()
+}
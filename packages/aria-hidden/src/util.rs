@@ -0,0 +1,21 @@
+use web_sys::{Element, HtmlCollection, NodeList, wasm_bindgen::JsCast};
+
+pub fn html_collection_to_elements(collection: HtmlCollection) -> Vec<Element> {
+    let mut elements: Vec<Element> = vec![];
+    for n in 0..collection.length() {
+        if let Some(element) = collection.item(n) {
+            elements.push(element);
+        }
+    }
+    elements
+}
+
+pub fn node_list_to_elements(node_list: NodeList) -> Vec<Element> {
+    let mut elements: Vec<Element> = vec![];
+    for n in 0..node_list.length() {
+        if let Some(element) = node_list.item(n).and_then(|node| node.dyn_into().ok()) {
+            elements.push(element);
+        }
+    }
+    elements
+}
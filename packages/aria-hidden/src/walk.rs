@@ -0,0 +1,669 @@
+use std::collections::HashMap;
+
+use crate::dom::Dom;
+
+/// Map keyed by node identity, because nodes such as `Element` do not implement `Hash`.
+pub struct NodeMap<D: Dom, V>(HashMap<D::Key, (D::Node, V)>);
+
+impl<D: Dom, V> NodeMap<D, V> {
+    pub fn new() -> Self {
+        NodeMap(HashMap::new())
+    }
+
+    pub fn get(&self, dom: &D, node: &D::Node) -> Option<&V> {
+        self.0.get(&dom.key(node)).map(|(_, value)| value)
+    }
+
+    pub fn insert(&mut self, dom: &D, node: D::Node, value: V) {
+        self.0.insert(dom.key(&node), (node, value));
+    }
+
+    pub fn remove(&mut self, dom: &D, node: &D::Node) -> Option<V> {
+        self.0.remove(&dom.key(node)).map(|(_, value)| value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(D::Node, V)> {
+        self.0.values()
+    }
+}
+
+impl<D: Dom> NodeMap<D, u64> {
+    pub fn increment(&mut self, dom: &D, node: &D::Node) -> u64 {
+        let entry = self
+            .0
+            .entry(dom.key(node))
+            .or_insert_with(|| (node.clone(), 0));
+        entry.1 += 1;
+        entry.1
+    }
+
+    pub fn decrement(&mut self, dom: &D, node: &D::Node) -> u64 {
+        let key = dom.key(node);
+        let value = self
+            .0
+            .get(&key)
+            .map(|(_, value)| *value)
+            .unwrap_or(1)
+            .saturating_sub(1);
+        if value == 0 {
+            self.0.remove(&key);
+        } else if let Some(entry) = self.0.get_mut(&key) {
+            entry.1 = value;
+        }
+        value
+    }
+}
+
+/// Set of nodes keyed by node identity.
+pub struct NodeSet<D: Dom>(NodeMap<D, ()>);
+
+impl<D: Dom> NodeSet<D> {
+    pub fn new() -> Self {
+        NodeSet(NodeMap::new())
+    }
+
+    pub fn contains(&self, dom: &D, node: &D::Node) -> bool {
+        self.0.get(dom, node).is_some()
+    }
+
+    pub fn insert(&mut self, dom: &D, node: D::Node) {
+        self.0.insert(dom, node, ());
+    }
+
+    pub fn drain(&mut self) -> impl Iterator<Item = D::Node> {
+        self.0.0.drain().map(|(_, (node, _))| node)
+    }
+}
+
+/// Bookkeeping shared by all locks.
+pub struct Registry<D: Dom> {
+    /// Counters per control attribute, then per node.
+    pub counter_map: HashMap<String, NodeMap<D, u64>>,
+    /// Original values per control attribute, then per node.
+    pub uncontrolled_nodes: HashMap<String, NodeMap<D, Option<String>>>,
+    /// Counters per marker name, then per node.
+    pub marker_map: HashMap<String, NodeMap<D, u64>>,
+    pub lock_count: u64,
+}
+
+impl<D: Dom> Registry<D> {
+    pub fn new() -> Self {
+        Registry {
+            counter_map: HashMap::new(),
+            uncontrolled_nodes: HashMap::new(),
+            marker_map: HashMap::new(),
+            lock_count: 0,
+        }
+    }
+}
+
+fn unwrap_host<D: Dom>(dom: &D, node: &D::Node) -> Option<D::Node> {
+    match dom.shadow_host(node) {
+        Some(host) => Some(host),
+        None => dom
+            .parent(node)
+            .and_then(|parent_node| unwrap_host(dom, &parent_node)),
+    }
+}
+
+pub fn correct_targets<D: Dom>(
+    dom: &D,
+    parent: &D::Node,
+    targets: Vec<D::Node>,
+    shadow_dom: bool,
+) -> Vec<D::Node> {
+    targets
+        .into_iter()
+        .filter_map(|target| {
+            if dom.contains(parent, &target) {
+                return Some(target);
+            }
+
+            let mut host = unwrap_host(dom, &target);
+
+            if shadow_dom {
+                // Keep the target itself, as long as one of its hosts is inside the parent.
+                while let Some(current_host) = host {
+                    if dom.contains(parent, &current_host) {
+                        return Some(target);
+                    }
+                    host = unwrap_host(dom, &current_host);
+                }
+
+                return None;
+            }
+
+            if let Some(corrected_target) = host
+                && dom.contains(parent, &corrected_target)
+            {
+                return Some(corrected_target);
+            }
+
+            None
+        })
+        .collect()
+}
+
+/// Whether the control attribute already hides the node.
+fn is_hidden_by<D: Dom>(dom: &D, node: &D::Node, control_attribute: &str) -> bool {
+    match control_attribute {
+        // Boolean attribute, so any value makes the element inert.
+        "inert" => dom.get_attribute(node, control_attribute).is_some(),
+        _ => dom
+            .get_attribute(node, control_attribute)
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("true")),
+    }
+}
+
+/// Nodes suppressed by a single lock.
+pub struct Walker<D: Dom> {
+    pub dom: D,
+    pub elements_to_keep: NodeSet<D>,
+    pub elements_to_stop: NodeSet<D>,
+    pub marker_name: String,
+    pub control_attribute: String,
+    pub shadow_dom: bool,
+    pub hidden_nodes: NodeSet<D>,
+    pub walked_nodes: Vec<D::Node>,
+}
+
+impl<D: Dom> Walker<D> {
+    /// Suppresses everything inside the parent except the targets, adding a lock.
+    pub fn lock(
+        dom: D,
+        registry: &mut Registry<D>,
+        parent: &D::Node,
+        targets: Vec<D::Node>,
+        marker_name: &str,
+        control_attribute: &str,
+        shadow_dom: bool,
+    ) -> Self {
+        let targets = correct_targets(&dom, parent, targets, shadow_dom);
+
+        let mut walker = Walker {
+            dom,
+            elements_to_keep: NodeSet::new(),
+            elements_to_stop: NodeSet::new(),
+            marker_name: marker_name.into(),
+            control_attribute: control_attribute.into(),
+            shadow_dom,
+            hidden_nodes: NodeSet::new(),
+            walked_nodes: vec![],
+        };
+
+        for target in targets {
+            walker.elements_to_stop.insert(&walker.dom, target.clone());
+            walker.keep(Some(target));
+        }
+
+        walker.deep(registry, parent);
+
+        registry.lock_count += 1;
+
+        walker
+    }
+
+    /// Restores everything suppressed by this walker and releases its lock.
+    pub fn unlock(&mut self, registry: &mut Registry<D>) {
+        self.restore(registry);
+
+        registry.lock_count = registry.lock_count.saturating_sub(1);
+        if registry.lock_count == 0 {
+            registry.counter_map.clear();
+            registry.uncontrolled_nodes.clear();
+            registry.marker_map.clear();
+        }
+    }
+
+    pub fn keep(&mut self, node: Option<D::Node>) {
+        if let Some(node) = node
+            && !self.elements_to_keep.contains(&self.dom, &node)
+        {
+            // Climb from a shadow root to its host, so hosts of targets in shadow trees are kept as well.
+            let parent_node = self
+                .dom
+                .shadow_host(&node)
+                .or_else(|| self.dom.parent(&node));
+            self.elements_to_keep.insert(&self.dom, node);
+            self.keep(parent_node);
+        }
+    }
+
+    pub fn deep(&mut self, registry: &mut Registry<D>, parent: &D::Node) {
+        if self.elements_to_stop.contains(&self.dom, parent) {
+            return;
+        }
+
+        self.walked_nodes.push(parent.clone());
+
+        for node in self.dom.children(parent) {
+            if self.elements_to_keep.contains(&self.dom, &node) {
+                self.deep(registry, &node);
+
+                if self.shadow_dom
+                    && let Some(shadow_root) = self.dom.shadow_root(&node)
+                    && self.elements_to_keep.contains(&self.dom, &shadow_root)
+                {
+                    self.deep(registry, &shadow_root);
+                }
+            } else {
+                self.suppress(registry, node);
+            }
+        }
+    }
+
+    pub fn suppress(&mut self, registry: &mut Registry<D>, node: D::Node) {
+        if self.hidden_nodes.contains(&self.dom, &node) {
+            return;
+        }
+
+        let controlled = registry
+            .counter_map
+            .get(&self.control_attribute)
+            .is_some_and(|counter| counter.get(&self.dom, &node).is_some());
+        if !controlled && is_hidden_by(&self.dom, &node, &self.control_attribute) {
+            // Hidden by the author, so it is left alone and never restored.
+            return;
+        }
+
+        let counter_value = registry
+            .counter_map
+            .entry(self.control_attribute.clone())
+            .or_insert_with(NodeMap::new)
+            .increment(&self.dom, &node);
+        let marker_value = registry
+            .marker_map
+            .entry(self.marker_name.clone())
+            .or_insert_with(NodeMap::new)
+            .increment(&self.dom, &node);
+
+        if counter_value == 1 {
+            registry
+                .uncontrolled_nodes
+                .entry(self.control_attribute.clone())
+                .or_insert_with(NodeMap::new)
+                .insert(
+                    &self.dom,
+                    node.clone(),
+                    self.dom.get_attribute(&node, &self.control_attribute),
+                );
+
+            if let Err(error) = self
+                .dom
+                .set_attribute(&node, &self.control_attribute, "true")
+            {
+                self.dom
+                    .report_error("aria-hidden: cannot operate on", &node, error);
+            }
+        }
+
+        if marker_value == 1
+            && let Err(error) = self.dom.set_attribute(&node, &self.marker_name, "true")
+        {
+            self.dom
+                .report_error("aria-hidden: cannot operate on", &node, error);
+        }
+
+        self.hidden_nodes.insert(&self.dom, node);
+    }
+
+    fn restore(&mut self, registry: &mut Registry<D>) {
+        let hidden_nodes = self.hidden_nodes.drain().collect::<Vec<_>>();
+        for node in hidden_nodes {
+            let counter_value = registry
+                .counter_map
+                .get_mut(&self.control_attribute)
+                .map(|counter| counter.decrement(&self.dom, &node))
+                .unwrap_or(0);
+            let marker_value = registry
+                .marker_map
+                .get_mut(&self.marker_name)
+                .map(|marker_counter| marker_counter.decrement(&self.dom, &node))
+                .unwrap_or(0);
+
+            if counter_value == 0 {
+                let original_value = registry
+                    .uncontrolled_nodes
+                    .get_mut(&self.control_attribute)
+                    .and_then(|original_values| original_values.remove(&self.dom, &node))
+                    .flatten();
+                let result = match original_value {
+                    Some(original_value) => {
+                        self.dom
+                            .set_attribute(&node, &self.control_attribute, &original_value)
+                    }
+                    None => self.dom.remove_attribute(&node, &self.control_attribute),
+                };
+                if let Err(error) = result {
+                    self.dom
+                        .report_error("aria-hidden: cannot restore", &node, error);
+                }
+            }
+
+            if marker_value == 0
+                && let Err(error) = self.dom.remove_attribute(&node, &self.marker_name)
+            {
+                self.dom
+                    .report_error("aria-hidden: cannot restore", &node, error);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{MemoryDom, MemoryNode};
+
+    const MARKER: &str = "data-aria-hidden";
+    const HIDDEN: &str = "aria-hidden";
+
+    /// `body > [header, main > [dialog, aside], footer]`
+    struct Page {
+        dom: MemoryDom,
+        body: MemoryNode,
+        header: MemoryNode,
+        main: MemoryNode,
+        dialog: MemoryNode,
+        aside: MemoryNode,
+        footer: MemoryNode,
+    }
+
+    fn page() -> Page {
+        let dom = MemoryDom::new();
+        let body = dom.create_element("body");
+        let header = dom.append_element(body, "header");
+        let main = dom.append_element(body, "main");
+        let dialog = dom.append_element(main, "div");
+        let aside = dom.append_element(main, "aside");
+        let footer = dom.append_element(body, "footer");
+
+        Page {
+            dom,
+            body,
+            header,
+            main,
+            dialog,
+            aside,
+            footer,
+        }
+    }
+
+    fn lock(
+        page: &Page,
+        registry: &mut Registry<MemoryDom>,
+        targets: Vec<MemoryNode>,
+        marker_name: &str,
+        control_attribute: &str,
+    ) -> Walker<MemoryDom> {
+        Walker::lock(
+            page.dom.clone(),
+            registry,
+            &page.body,
+            targets,
+            marker_name,
+            control_attribute,
+            false,
+        )
+    }
+
+    fn attribute(page: &Page, node: MemoryNode, name: &str) -> Option<String> {
+        page.dom.get_attribute(&node, name)
+    }
+
+    #[test]
+    fn suppresses_everything_except_targets_and_ancestors() {
+        let page = page();
+        let mut registry = Registry::new();
+
+        let mut walker = lock(&page, &mut registry, vec![page.dialog], MARKER, HIDDEN);
+
+        for node in [page.header, page.aside, page.footer] {
+            assert_eq!(attribute(&page, node, HIDDEN).as_deref(), Some("true"));
+            assert_eq!(attribute(&page, node, MARKER).as_deref(), Some("true"));
+        }
+        for node in [page.body, page.main, page.dialog] {
+            assert_eq!(attribute(&page, node, HIDDEN), None);
+            assert_eq!(attribute(&page, node, MARKER), None);
+        }
+        assert_eq!(registry.lock_count, 1);
+
+        walker.unlock(&mut registry);
+
+        for node in [page.header, page.aside, page.footer] {
+            assert_eq!(attribute(&page, node, HIDDEN), None);
+            assert_eq!(attribute(&page, node, MARKER), None);
+        }
+        assert_eq!(registry.lock_count, 0);
+    }
+
+    #[test]
+    fn nested_locks_restore_after_last_release() {
+        let page = page();
+        let mut registry = Registry::new();
+
+        let mut outer = lock(&page, &mut registry, vec![page.main], MARKER, HIDDEN);
+        let mut inner = lock(&page, &mut registry, vec![page.dialog], MARKER, HIDDEN);
+
+        assert_eq!(registry.lock_count, 2);
+        assert_eq!(
+            registry.counter_map[HIDDEN]
+                .get(&page.dom, &page.header)
+                .copied(),
+            Some(2)
+        );
+        assert_eq!(
+            attribute(&page, page.aside, HIDDEN).as_deref(),
+            Some("true")
+        );
+
+        inner.unlock(&mut registry);
+
+        assert_eq!(attribute(&page, page.aside, HIDDEN), None);
+        assert_eq!(
+            attribute(&page, page.header, HIDDEN).as_deref(),
+            Some("true")
+        );
+        assert_eq!(
+            attribute(&page, page.header, MARKER).as_deref(),
+            Some("true")
+        );
+
+        outer.unlock(&mut registry);
+
+        assert_eq!(attribute(&page, page.header, HIDDEN), None);
+        assert_eq!(attribute(&page, page.header, MARKER), None);
+        assert!(registry.counter_map.is_empty());
+        assert!(registry.marker_map.is_empty());
+        assert!(registry.uncontrolled_nodes.is_empty());
+    }
+
+    #[test]
+    fn nested_locks_released_out_of_order() {
+        let page = page();
+        let mut registry = Registry::new();
+
+        let mut outer = lock(&page, &mut registry, vec![page.main], MARKER, HIDDEN);
+        let mut inner = lock(&page, &mut registry, vec![page.dialog], MARKER, HIDDEN);
+
+        outer.unlock(&mut registry);
+
+        // Still suppressed by the inner lock.
+        for node in [page.header, page.aside, page.footer] {
+            assert_eq!(attribute(&page, node, HIDDEN).as_deref(), Some("true"));
+        }
+        assert_eq!(registry.lock_count, 1);
+
+        inner.unlock(&mut registry);
+
+        for node in [page.header, page.aside, page.footer] {
+            assert_eq!(attribute(&page, node, HIDDEN), None);
+            assert_eq!(attribute(&page, node, MARKER), None);
+        }
+        assert_eq!(registry.lock_count, 0);
+    }
+
+    #[test]
+    fn markers_are_counted_separately() {
+        let page = page();
+        let mut registry = Registry::new();
+
+        let mut first = lock(&page, &mut registry, vec![page.main], "data-first", HIDDEN);
+        let mut second = lock(&page, &mut registry, vec![page.main], "data-second", HIDDEN);
+
+        first.unlock(&mut registry);
+
+        assert_eq!(attribute(&page, page.header, "data-first"), None);
+        assert_eq!(
+            attribute(&page, page.header, "data-second").as_deref(),
+            Some("true")
+        );
+        assert_eq!(
+            attribute(&page, page.header, HIDDEN).as_deref(),
+            Some("true")
+        );
+
+        second.unlock(&mut registry);
+
+        assert_eq!(attribute(&page, page.header, "data-second"), None);
+        assert_eq!(attribute(&page, page.header, HIDDEN), None);
+    }
+
+    #[test]
+    fn restores_original_values() {
+        let page = page();
+        let mut registry = Registry::new();
+        page.dom
+            .set_attribute(&page.header, HIDDEN, "false")
+            .unwrap();
+
+        let mut outer = lock(&page, &mut registry, vec![page.main], MARKER, HIDDEN);
+        let mut inner = lock(&page, &mut registry, vec![page.dialog], MARKER, HIDDEN);
+
+        assert_eq!(
+            attribute(&page, page.header, HIDDEN).as_deref(),
+            Some("true")
+        );
+
+        outer.unlock(&mut registry);
+        inner.unlock(&mut registry);
+
+        assert_eq!(
+            attribute(&page, page.header, HIDDEN).as_deref(),
+            Some("false")
+        );
+    }
+
+    #[test]
+    fn leaves_author_hidden_elements_alone() {
+        let page = page();
+        let mut registry = Registry::new();
+        page.dom
+            .set_attribute(&page.header, HIDDEN, " TRUE ")
+            .unwrap();
+        page.dom.set_attribute(&page.footer, "inert", "").unwrap();
+
+        let mut hide = lock(&page, &mut registry, vec![page.dialog], MARKER, HIDDEN);
+        let mut inert = lock(&page, &mut registry, vec![page.dialog], MARKER, "inert");
+
+        assert_eq!(attribute(&page, page.header, MARKER), Some("true".into()));
+        assert!(
+            registry.counter_map[HIDDEN]
+                .get(&page.dom, &page.header)
+                .is_none()
+        );
+        assert!(
+            registry.counter_map["inert"]
+                .get(&page.dom, &page.footer)
+                .is_none()
+        );
+
+        hide.unlock(&mut registry);
+        inert.unlock(&mut registry);
+
+        assert_eq!(
+            attribute(&page, page.header, HIDDEN).as_deref(),
+            Some(" TRUE ")
+        );
+        assert_eq!(attribute(&page, page.footer, "inert").as_deref(), Some(""));
+        assert_eq!(attribute(&page, page.header, MARKER), None);
+    }
+
+    #[test]
+    fn keeps_selected_descendants() {
+        let page = page();
+        let mut registry = Registry::new();
+        let status = page.dom.append_element(page.footer, "div");
+        page.dom
+            .set_attribute(&status, "aria-live", "polite")
+            .unwrap();
+
+        let mut targets = vec![page.dialog];
+        targets.extend(
+            page.dom
+                .query_selector_all(&page.body, "[aria-live]")
+                .unwrap(),
+        );
+        let mut walker = lock(&page, &mut registry, targets, MARKER, HIDDEN);
+
+        assert_eq!(attribute(&page, page.footer, HIDDEN), None);
+        assert_eq!(attribute(&page, status, HIDDEN), None);
+        assert_eq!(
+            attribute(&page, page.header, HIDDEN).as_deref(),
+            Some("true")
+        );
+
+        walker.unlock(&mut registry);
+    }
+
+    #[test]
+    fn corrects_targets_in_shadow_trees() {
+        let page = page();
+        let mut registry = Registry::new();
+        let shadow_root = page.dom.attach_shadow(page.aside);
+        let button = page.dom.append_element(shadow_root, "button");
+        let sibling = page.dom.append_element(shadow_root, "span");
+
+        // Without shadow DOM support the host is kept instead of the target.
+        let mut walker = lock(&page, &mut registry, vec![button], MARKER, HIDDEN);
+
+        assert_eq!(attribute(&page, page.aside, HIDDEN), None);
+        assert_eq!(
+            attribute(&page, page.dialog, HIDDEN).as_deref(),
+            Some("true")
+        );
+        assert_eq!(attribute(&page, sibling, HIDDEN), None);
+
+        walker.unlock(&mut registry);
+
+        let mut walker = Walker::lock(
+            page.dom.clone(),
+            &mut registry,
+            &page.body,
+            vec![button],
+            MARKER,
+            HIDDEN,
+            true,
+        );
+
+        assert_eq!(attribute(&page, page.aside, HIDDEN), None);
+        assert_eq!(attribute(&page, button, HIDDEN), None);
+        assert_eq!(attribute(&page, sibling, HIDDEN).as_deref(), Some("true"));
+
+        walker.unlock(&mut registry);
+
+        assert_eq!(attribute(&page, sibling, HIDDEN), None);
+    }
+
+    #[test]
+    fn drops_detached_targets() {
+        let page = page();
+        let detached = page.dom.create_element("div");
+
+        assert!(correct_targets(&page.dom, &page.body, vec![detached], false).is_empty());
+        assert_eq!(
+            correct_targets(&page.dom, &page.body, vec![detached, page.dialog], true),
+            vec![page.dialog]
+        );
+    }
+}
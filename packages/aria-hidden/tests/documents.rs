@@ -0,0 +1,114 @@
+mod util;
+
+use aria_hidden::{SuppressOthersOptions, hide_others};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{Document, HtmlIFrameElement, wasm_bindgen::JsCast};
+
+use crate::util::{Rendered, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const HTML: &str = r#"<iframe id="frame"></iframe><div id="sibling"></div>"#;
+
+/// Document of the same-origin iframe, with the HTML in its body.
+fn frame_document(rendered: &Rendered, html: &str) -> Document {
+    let document = rendered
+        .get("frame")
+        .unchecked_into::<HtmlIFrameElement>()
+        .content_document()
+        .expect("Frame document should exist.");
+    document
+        .body()
+        .expect("Body should exist.")
+        .set_inner_html(html);
+    document
+}
+
+#[wasm_bindgen_test]
+fn suppresses_parent_documents() {
+    let rendered = render(HTML);
+    let document = frame_document(
+        &rendered,
+        r#"<div id="target"></div><div id="frame-sibling"></div>"#,
+    );
+    let element = |id: &str| {
+        document
+            .get_element_by_id(id)
+            .expect("Element should exist.")
+    };
+
+    let mut guard = hide_others(
+        vec![element("target")],
+        None,
+        None,
+        SuppressOthersOptions {
+            parent_documents: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Elements should be hidden.");
+
+    assert_eq!(
+        Some("true".into()),
+        element("frame-sibling").get_attribute("aria-hidden")
+    );
+    // The iframe contains the target, so it is kept.
+    assert_eq!(None, rendered.get("frame").get_attribute("aria-hidden"));
+    assert_eq!(
+        Some("true".into()),
+        rendered.get("sibling").get_attribute("aria-hidden")
+    );
+
+    // A single guard restores every document.
+    guard.release();
+    assert_eq!(None, element("frame-sibling").get_attribute("aria-hidden"));
+    assert_eq!(None, rendered.get("sibling").get_attribute("aria-hidden"));
+}
+
+#[wasm_bindgen_test]
+fn ignores_parent_documents_by_default() {
+    let rendered = render(HTML);
+    let document = frame_document(&rendered, r#"<div id="target"></div>"#);
+
+    let _guard = hide_others(
+        vec![
+            document
+                .get_element_by_id("target")
+                .expect("Element should exist."),
+        ],
+        None,
+        None,
+        SuppressOthersOptions::default(),
+    )
+    .expect("Elements should be hidden.");
+
+    assert_eq!(None, rendered.get("sibling").get_attribute("aria-hidden"));
+}
+
+#[wasm_bindgen_test]
+fn suppresses_listed_documents() {
+    let rendered = render(r#"<div id="target"></div><iframe id="frame"></iframe>"#);
+    let document = frame_document(&rendered, r#"<div id="frame-content"></div>"#);
+
+    let mut guard = hide_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions {
+            documents: vec![document.clone()],
+            ..Default::default()
+        },
+    )
+    .expect("Elements should be hidden.");
+
+    let frame_content = document
+        .get_element_by_id("frame-content")
+        .expect("Element should exist.");
+    assert_eq!(
+        Some("true".into()),
+        frame_content.get_attribute("aria-hidden")
+    );
+
+    guard.release();
+    assert_eq!(None, frame_content.get_attribute("aria-hidden"));
+}
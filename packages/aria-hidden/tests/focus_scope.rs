@@ -0,0 +1,54 @@
+mod util;
+
+use aria_hidden::{SuppressOthersOptions, inert_others_with_focus_scope};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{Element, HtmlElement, wasm_bindgen::JsCast};
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn active_element(element: &Element) -> Option<Element> {
+    element
+        .owner_document()
+        .expect("Document should exist.")
+        .active_element()
+}
+
+#[wasm_bindgen_test]
+fn inert_others_and_focus() {
+    let rendered =
+        render(r#"<button id="outside">Open</button><div id="dialog"><input id="input"></div>"#);
+    let outside = rendered.get("outside");
+    outside
+        .unchecked_ref::<HtmlElement>()
+        .focus()
+        .expect("Element should be focused.");
+
+    let mut guard = inert_others_with_focus_scope(
+        vec![rendered.get("dialog")],
+        None,
+        None,
+        SuppressOthersOptions::default(),
+    )
+    .expect("Elements should be inert.");
+
+    assert!(outside.has_attribute("inert"));
+    assert_eq!(Some(rendered.get("input")), active_element(&outside));
+
+    guard.release();
+    assert!(!outside.has_attribute("inert"));
+    assert_eq!(Some(outside.clone()), active_element(&outside));
+}
+
+#[wasm_bindgen_test]
+fn detached_targets() {
+    let rendered = render(r#"<div id="dialog"></div>"#);
+    let dialog = rendered.get("dialog");
+    dialog.remove();
+
+    assert!(
+        inert_others_with_focus_scope(vec![dialog], None, None, SuppressOthersOptions::default())
+            .is_err()
+    );
+}
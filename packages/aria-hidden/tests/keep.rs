@@ -0,0 +1,120 @@
+mod util;
+
+use std::rc::Rc;
+
+use aria_hidden::{SuppressOthersOptions, hide_others, inert_others};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const HTML: &str = r#"
+    <div id="target"></div>
+    <div id="live" aria-live="polite"></div>
+    <div id="wrapper"><span id="status" role="status"></span><span id="sibling"></span></div>
+    <div id="announcer" data-announcer></div>
+    <div id="other"></div>
+"#;
+
+#[wasm_bindgen_test]
+fn keeps_aria_live_by_default() {
+    let rendered = render(HTML);
+
+    let _guard = hide_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions::default(),
+    )
+    .expect("Elements should be hidden.");
+
+    assert_eq!(None, rendered.get("live").get_attribute("aria-hidden"));
+    assert_eq!(
+        Some("true".into()),
+        rendered.get("other").get_attribute("aria-hidden")
+    );
+}
+
+#[wasm_bindgen_test]
+fn hides_aria_live() {
+    let rendered = render(HTML);
+
+    let _guard = hide_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions {
+            keep_aria_live: Some(false),
+            ..Default::default()
+        },
+    )
+    .expect("Elements should be hidden.");
+
+    assert_eq!(
+        Some("true".into()),
+        rendered.get("live").get_attribute("aria-hidden")
+    );
+}
+
+#[wasm_bindgen_test]
+fn keeps_selectors() {
+    let rendered = render(HTML);
+
+    let _guard = hide_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions {
+            keep_selectors: vec!["[role=status]".into()],
+            ..Default::default()
+        },
+    )
+    .expect("Elements should be hidden.");
+
+    // Ancestors of kept elements are walked instead of hidden.
+    assert_eq!(None, rendered.get("wrapper").get_attribute("aria-hidden"));
+    assert_eq!(None, rendered.get("status").get_attribute("aria-hidden"));
+    assert_eq!(
+        Some("true".into()),
+        rendered.get("sibling").get_attribute("aria-hidden")
+    );
+}
+
+#[wasm_bindgen_test]
+fn keeps_predicate() {
+    let rendered = render(HTML);
+
+    let _guard = hide_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions {
+            keep: Some(Rc::new(|element| element.has_attribute("data-announcer"))),
+            ..Default::default()
+        },
+    )
+    .expect("Elements should be hidden.");
+
+    assert_eq!(None, rendered.get("announcer").get_attribute("aria-hidden"));
+    assert_eq!(
+        Some("true".into()),
+        rendered.get("other").get_attribute("aria-hidden")
+    );
+}
+
+#[wasm_bindgen_test]
+fn inert_keeps_aria_live() {
+    let rendered = render(HTML);
+
+    let _guard = inert_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions::default(),
+    )
+    .expect("Elements should be inert.");
+
+    assert!(!rendered.get("live").has_attribute("inert"));
+    assert!(rendered.get("other").has_attribute("inert"));
+}
@@ -0,0 +1,101 @@
+mod util;
+
+use aria_hidden::{SuppressOthersOptions, hide_others};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{Element, js_sys::Promise, wasm_bindgen::JsValue};
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Waits for pending microtasks, such as mutation observer callbacks.
+async fn flush_microtasks() {
+    JsFuture::from(Promise::resolve(&JsValue::UNDEFINED))
+        .await
+        .expect("Promise should resolve.");
+}
+
+fn insert(parent: &Element, html: &str) -> Element {
+    let document = parent.owner_document().expect("Document should exist.");
+    let element = document
+        .create_element("div")
+        .expect("Element should be created.");
+    element.set_inner_html(html);
+    parent
+        .append_child(&element)
+        .expect("Element should be appended.");
+    element
+}
+
+fn live_options() -> SuppressOthersOptions {
+    SuppressOthersOptions {
+        live: Some(true),
+        ..Default::default()
+    }
+}
+
+const HTML: &str = r#"<div id="root"><div id="target"></div></div>"#;
+
+#[wasm_bindgen_test]
+async fn hides_inserted_siblings() {
+    let rendered = render(HTML);
+    let root = rendered.get("root");
+
+    let mut guard = hide_others(vec![rendered.get("target")], None, None, live_options())
+        .expect("Elements should be hidden.");
+
+    let inserted = insert(&root, "");
+    flush_microtasks().await;
+    assert_eq!(Some("true".into()), inserted.get_attribute("aria-hidden"));
+
+    guard.release();
+    assert_eq!(None, inserted.get_attribute("aria-hidden"));
+
+    // The observer is disconnected on release.
+    let inserted = insert(&root, "");
+    flush_microtasks().await;
+    assert_eq!(None, inserted.get_attribute("aria-hidden"));
+}
+
+#[wasm_bindgen_test]
+async fn ignores_insertions_without_live() {
+    let rendered = render(HTML);
+
+    let _guard = hide_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions::default(),
+    )
+    .expect("Elements should be hidden.");
+
+    let inserted = insert(&rendered.get("root"), "");
+    flush_microtasks().await;
+    assert_eq!(None, inserted.get_attribute("aria-hidden"));
+}
+
+#[wasm_bindgen_test]
+async fn keeps_inserted_aria_live() {
+    let rendered = render(HTML);
+
+    let _guard = hide_others(vec![rendered.get("target")], None, None, live_options())
+        .expect("Elements should be hidden.");
+
+    let inserted = insert(
+        &rendered.get("root"),
+        r#"<div id="live" aria-live="polite"></div><span id="sibling"></span>"#,
+    );
+    flush_microtasks().await;
+    assert_eq!(None, inserted.get_attribute("aria-hidden"));
+    assert_eq!(None, rendered.get("live").get_attribute("aria-hidden"));
+    assert_eq!(
+        Some("true".into()),
+        rendered.get("sibling").get_attribute("aria-hidden")
+    );
+
+    // The inserted element is walked, so insertions into it are observed too.
+    let nested = insert(&inserted, "");
+    flush_microtasks().await;
+    assert_eq!(Some("true".into()), nested.get_attribute("aria-hidden"));
+}
@@ -0,0 +1,109 @@
+mod util;
+
+use aria_hidden::{Strategy, StrategyDecision, SuppressOthersOptions, Suppressor};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{HtmlDialogElement, wasm_bindgen::JsCast};
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const DIALOG_HTML: &str = r#"<dialog id="dialog"><button id="button">Close</button><span id="inner"></span></dialog><div id="outer"></div>"#;
+
+#[wasm_bindgen_test]
+fn modal_dialog_target() {
+    let rendered = render(DIALOG_HTML);
+    let dialog = rendered.get("dialog").unchecked_into::<HtmlDialogElement>();
+    dialog.show_modal().expect("Dialog should open.");
+
+    let suppressor = Suppressor::new()
+        .targets([&dialog])
+        .strategy(Strategy::Hide);
+    assert_eq!(StrategyDecision::NativeModal, suppressor.decision());
+
+    let guard = suppressor.apply().expect("Elements should be suppressed.");
+    assert!(!guard.is_active());
+    assert_eq!(None, rendered.get("outer").get_attribute("aria-hidden"));
+
+    dialog.close();
+}
+
+#[wasm_bindgen_test]
+fn non_modal_dialog_target() {
+    let rendered = render(DIALOG_HTML);
+    let dialog = rendered.get("dialog").unchecked_into::<HtmlDialogElement>();
+    dialog.show();
+
+    let suppressor = Suppressor::new()
+        .targets([&dialog])
+        .strategy(Strategy::Hide);
+    assert_eq!(StrategyDecision::Hide, suppressor.decision());
+
+    let guard = suppressor.apply().expect("Elements should be suppressed.");
+    assert!(guard.is_active());
+    assert_eq!(
+        Some("true".into()),
+        rendered.get("outer").get_attribute("aria-hidden")
+    );
+
+    drop(guard);
+    dialog.close();
+}
+
+#[wasm_bindgen_test]
+fn element_target() {
+    let rendered = render(DIALOG_HTML);
+
+    let suppressor = Suppressor::new()
+        .targets([rendered.get("outer")])
+        .strategy(Strategy::Hide);
+    assert_eq!(StrategyDecision::Hide, suppressor.decision());
+}
+
+#[wasm_bindgen_test]
+fn target_inside_modal_dialog() {
+    let rendered = render(DIALOG_HTML);
+    let dialog = rendered.get("dialog").unchecked_into::<HtmlDialogElement>();
+    dialog.show_modal().expect("Dialog should open.");
+
+    let guard = Suppressor::new()
+        .targets([rendered.get("button")])
+        .strategy(Strategy::Hide)
+        .apply()
+        .expect("Elements should be suppressed.");
+
+    // Only the content of the modal dialog is suppressed, the browser already makes everything outside inert.
+    assert_eq!(
+        Some("true".into()),
+        rendered.get("inner").get_attribute("aria-hidden")
+    );
+    assert_eq!(None, rendered.get("outer").get_attribute("aria-hidden"));
+
+    drop(guard);
+    dialog.close();
+}
+
+#[wasm_bindgen_test]
+fn native_modal_disabled() {
+    let rendered = render(DIALOG_HTML);
+    let dialog = rendered.get("dialog").unchecked_into::<HtmlDialogElement>();
+    dialog.show_modal().expect("Dialog should open.");
+
+    let suppressor = Suppressor::new()
+        .targets([&dialog])
+        .strategy(Strategy::Hide)
+        .options(SuppressOthersOptions {
+            native_modal: Some(false),
+            ..Default::default()
+        });
+    assert_eq!(StrategyDecision::Hide, suppressor.decision());
+
+    let guard = suppressor.apply().expect("Elements should be suppressed.");
+    assert_eq!(
+        Some("true".into()),
+        rendered.get("outer").get_attribute("aria-hidden")
+    );
+
+    drop(guard);
+    dialog.close();
+}
@@ -0,0 +1,101 @@
+mod util;
+
+use aria_hidden::{SuppressOthersOptions, hide_others};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{Element, ShadowRoot, ShadowRootInit, ShadowRootMode};
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn attach_shadow(host: &Element, html: &str) -> ShadowRoot {
+    let shadow_root = host
+        .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+        .expect("Shadow root should be attached.");
+    shadow_root.set_inner_html(html);
+    shadow_root
+}
+
+fn shadow_element(shadow_root: &ShadowRoot, id: &str) -> Element {
+    shadow_root
+        .get_element_by_id(id)
+        .expect("Element should exist.")
+}
+
+const SHADOW_HTML: &str = r#"<div id="live" aria-live="polite"></div><span id="sibling"></span>"#;
+
+#[wasm_bindgen_test]
+fn keeps_aria_live_in_shadow_root() {
+    let rendered = render(r#"<div id="target"></div><div id="host"></div>"#);
+    let host = rendered.get("host");
+    let shadow_root = attach_shadow(&host, SHADOW_HTML);
+
+    let _guard = hide_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions {
+            shadow_dom: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Elements should be hidden.");
+
+    assert_eq!(None, host.get_attribute("aria-hidden"));
+    assert_eq!(
+        None,
+        shadow_element(&shadow_root, "live").get_attribute("aria-hidden")
+    );
+    assert_eq!(
+        Some("true".into()),
+        shadow_element(&shadow_root, "sibling").get_attribute("aria-hidden")
+    );
+}
+
+#[wasm_bindgen_test]
+fn keeps_selectors_in_nested_shadow_roots() {
+    let rendered = render(r#"<div id="target"></div><div id="host"></div>"#);
+    let shadow_root = attach_shadow(&rendered.get("host"), r#"<div id="inner-host"></div>"#);
+    let inner_shadow_root = attach_shadow(
+        &shadow_element(&shadow_root, "inner-host"),
+        r#"<div id="status" role="status"></div><span id="sibling"></span>"#,
+    );
+
+    let _guard = hide_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions {
+            keep_selectors: vec!["[role=status]".into()],
+            shadow_dom: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Elements should be hidden.");
+
+    assert_eq!(
+        None,
+        shadow_element(&inner_shadow_root, "status").get_attribute("aria-hidden")
+    );
+    assert_eq!(
+        Some("true".into()),
+        shadow_element(&inner_shadow_root, "sibling").get_attribute("aria-hidden")
+    );
+}
+
+#[wasm_bindgen_test]
+fn hides_host_without_shadow_dom() {
+    let rendered = render(r#"<div id="target"></div><div id="host"></div>"#);
+    let host = rendered.get("host");
+    attach_shadow(&host, SHADOW_HTML);
+
+    let _guard = hide_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions::default(),
+    )
+    .expect("Elements should be hidden.");
+
+    assert_eq!(Some("true".into()), host.get_attribute("aria-hidden"));
+}
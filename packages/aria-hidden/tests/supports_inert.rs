@@ -0,0 +1,64 @@
+mod util;
+
+use aria_hidden::{
+    StrategyDecision, SuppressOthersOptions, Suppressor, override_supports_inert, supports_inert,
+    supress_others,
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const HTML: &str = r#"<div id="target"></div><div id="sibling"></div>"#;
+
+#[wasm_bindgen_test]
+fn detects_inert() {
+    // Every browser the tests run in supports inert.
+    assert!(supports_inert());
+
+    let rendered = render(HTML);
+    let _guard = supress_others(
+        vec![rendered.get("target")],
+        None,
+        None,
+        SuppressOthersOptions::default(),
+    )
+    .expect("Elements should be suppressed.");
+
+    let sibling = rendered.get("sibling");
+    assert!(sibling.has_attribute("inert"));
+    assert!(sibling.has_attribute("data-inert-ed"));
+    assert_eq!(None, sibling.get_attribute("aria-hidden"));
+}
+
+#[wasm_bindgen_test]
+fn overrides_detection() {
+    let rendered = render(HTML);
+    let suppressor = Suppressor::new().targets([rendered.get("target")]);
+
+    override_supports_inert(Some(false));
+    assert!(!supports_inert());
+    assert_eq!(StrategyDecision::Hide, suppressor.decision());
+
+    {
+        let _guard = supress_others(
+            vec![rendered.get("target")],
+            None,
+            None,
+            SuppressOthersOptions::default(),
+        )
+        .expect("Elements should be suppressed.");
+
+        let sibling = rendered.get("sibling");
+        assert_eq!(Some("true".into()), sibling.get_attribute("aria-hidden"));
+        assert!(!sibling.has_attribute("inert"));
+    }
+
+    override_supports_inert(Some(true));
+    assert_eq!(StrategyDecision::Inert, suppressor.decision());
+
+    override_supports_inert(None);
+    assert!(supports_inert());
+    assert_eq!(StrategyDecision::Inert, suppressor.decision());
+}
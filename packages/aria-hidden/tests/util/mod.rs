@@ -0,0 +1,44 @@
+use web_sys::{Element, window};
+
+/// Container rendered in the document body, which is removed from the document when dropped.
+///
+/// Removing the container keeps IDs unique in the document shared by all tests.
+pub struct Rendered {
+    container: Element,
+}
+
+impl Rendered {
+    /// Element with the ID inside the container.
+    pub fn get(&self, id: &str) -> Element {
+        self.container
+            .query_selector(&format!("#{id}"))
+            .expect("Selector should be valid.")
+            .unwrap_or_else(|| panic!("Element with ID `{id}` should exist."))
+    }
+}
+
+impl Drop for Rendered {
+    fn drop(&mut self) {
+        self.container.remove();
+    }
+}
+
+/// Renders the HTML in a new container in the document body.
+pub fn render(html: &str) -> Rendered {
+    let document = window()
+        .expect("Window should exist.")
+        .document()
+        .expect("Document should exist.");
+
+    let container = document
+        .create_element("div")
+        .expect("Element should be created.");
+    container.set_inner_html(html);
+    document
+        .body()
+        .expect("Body should exist.")
+        .append_child(&container)
+        .expect("Element should be appended.");
+
+    Rendered { container }
+}
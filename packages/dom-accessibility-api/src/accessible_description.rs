@@ -1,18 +1,32 @@
-use web_sys::Element;
+use web_sys::{Element, Node};
 
 use crate::{
     accessible_name_and_description::{
         Compute, ComputeTextAlternativeOptions, compute_text_alternative,
     },
-    util::query_id_refs,
+    caches::Caches,
 };
 
-/// Implements <https://w3c.github.io/accname/#mapping_additional_nd_description>.
-pub fn compute_accessible_description(
+fn unconsulted_attribute(
+    consulted_nodes: &[Node],
+    element: &Element,
+    attribute_name: &str,
+) -> Option<String> {
+    element
+        .get_attribute_node(attribute_name)
+        .filter(|attribute| !consulted_nodes.contains(attribute))
+        .map(|attribute| attribute.value())
+}
+
+pub fn description_with_caches(
     root: &Element,
     options: ComputeTextAlternativeOptions,
+    caches: &Caches,
+    consulted_nodes: &[Node],
 ) -> String {
-    let mut description = query_id_refs(root, "aria-describedby")
+    // Only the attributes consulted for the accessible name are skipped, referenced elements are computed on their own.
+    let mut description = caches
+        .query_id_refs(root, "aria-describedby")
         .iter()
         .map(|element| {
             compute_text_alternative(
@@ -21,18 +35,18 @@ pub fn compute_accessible_description(
                     compute: Some(Compute::Description),
                     ..options.clone()
                 },
+                caches,
+                &mut vec![],
             )
         })
         .collect::<Vec<_>>()
         .join(" ");
 
-    // TODO: Technically we need to make sure that node wasn't used for the accessible name.
-    //       This causes `description_1.0_combobox-focusable-manual` to fail.
-
     // https://w3c.github.io/aria/#aria-description
     // Mentions that aria-description should only be calculated if aria-describedby didn't provide a description.
     if description.is_empty()
-        && let Some(aria_description) = root.get_attribute("aria-description")
+        && let Some(aria_description) =
+            unconsulted_attribute(consulted_nodes, root, "aria-description")
     {
         description = aria_description;
     }
@@ -40,10 +54,20 @@ pub fn compute_accessible_description(
     // https://www.w3.org/TR/html-aam-1.0/#accessible-name-and-description-computation
     // Says for so many elements to use the `title` that we assume all elements are considered.
     if description.is_empty()
-        && let Some(title) = root.get_attribute("title")
+        && let Some(title) = unconsulted_attribute(consulted_nodes, root, "title")
     {
         description = title;
     }
 
     description
 }
+
+/// Implements <https://w3c.github.io/accname/#mapping_additional_nd_description>.
+pub fn compute_accessible_description(
+    root: &Element,
+    options: ComputeTextAlternativeOptions,
+) -> String {
+    let caches = Caches::new(&options);
+
+    description_with_caches(root, options, &caches, &[])
+}
@@ -1,36 +1,40 @@
-use web_sys::Element;
+use aria_query::AriaNameFromSource;
+use web_sys::{Element, Node};
 
 use crate::{
     accessible_name_and_description::{ComputeTextAlternativeOptions, compute_text_alternative},
-    util::has_any_concrete_roles,
+    caches::Caches,
+    get_role::get_explicit_role,
+    util::{PRESENTATION_ROLES, name_from},
 };
 
 /// Implements <https://w3c.github.io/aria/#namefromprohibited>.
-fn prohibits_naming(element: &Element) -> bool {
-    has_any_concrete_roles(
-        element,
-        vec![
-            "caption",
-            "code",
-            "deletion",
-            "emphasis",
-            "generic",
-            "insertion",
-            "none",
-            "paragraph",
-            "presentation",
-            "strong",
-            "subscript",
-            "superscript",
-        ],
-    )
+///
+/// Like upstream, implicit roles such as `generic` of `div` and `span` or `caption` of `caption` do not prohibit
+/// naming, only explicit roles do. Presentational roles prohibit naming either way, e.g. for `<img alt="">`.
+fn prohibits_naming(caches: &Caches, element: &Element) -> bool {
+    caches.role(element).is_some_and(|role| {
+        name_from(role).contains(&AriaNameFromSource::Prohibited)
+            && (PRESENTATION_ROLES.contains(&role) || get_explicit_role(element) == Some(role))
+    })
 }
 
-/// Implements <https://w3c.github.io/accname/#mapping_additional_nd_name>.
-pub fn compute_accessible_name(root: &Element, options: ComputeTextAlternativeOptions) -> String {
-    if prohibits_naming(root) {
+pub fn name_with_caches(
+    root: &Element,
+    options: ComputeTextAlternativeOptions,
+    caches: &Caches,
+    consulted_nodes: &mut Vec<Node>,
+) -> String {
+    if prohibits_naming(caches, root) {
         "".into()
     } else {
-        compute_text_alternative(root, options)
+        compute_text_alternative(root, options, caches, consulted_nodes)
     }
 }
+
+/// Implements <https://w3c.github.io/accname/#mapping_additional_nd_name>.
+pub fn compute_accessible_name(root: &Element, options: ComputeTextAlternativeOptions) -> String {
+    let caches = Caches::new(&options);
+
+    name_with_caches(root, options, &caches, &mut vec![])
+}
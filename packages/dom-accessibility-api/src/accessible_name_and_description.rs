@@ -1,16 +1,25 @@
-use std::rc::Rc;
+use std::{rc::Rc, sync::LazyLock};
 
+use aria_query::{AriaAbstractRole, AriaNameFromSource, AriaRole, AriaRoleDefinitionSuperClass};
 use regex::Regex;
 use web_sys::{
     CssStyleDeclaration, Element, HtmlFieldSetElement, HtmlInputElement, HtmlLabelElement,
     HtmlLegendElement, HtmlOptGroupElement, HtmlSelectElement, HtmlSlotElement,
     HtmlTableCaptionElement, HtmlTableElement, HtmlTextAreaElement, Node, SvgElement,
-    SvgTitleElement, wasm_bindgen::JsCast, window,
+    SvgTitleElement, wasm_bindgen::JsCast,
 };
 
-use crate::util::{
-    PRESENTATION_ROLES, array_to_vec, has_any_concrete_roles, html_collection_to_vec,
-    node_list_to_vec, query_id_refs,
+use crate::{
+    accessible_description::description_with_caches,
+    accessible_name::name_with_caches,
+    caches::Caches,
+    computed_style_cache::ComputedStyleCache,
+    is_inaccessible::{is_subtree_inaccessible, is_visibility_hidden},
+    locale::DefaultNameProvider,
+    util::{
+        PRESENTATION_ROLES, array_to_vec, has_any_concrete_roles, html_collection_to_vec,
+        is_form_associated_custom_element, name_from, node_list_to_vec, role_definition,
+    },
 };
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -21,6 +30,8 @@ pub enum Compute {
 
 pub type GetComputedStyle = Rc<dyn Fn(&Element, Option<&str>) -> CssStyleDeclaration>;
 
+pub type IsFormAssociated = Rc<dyn Fn(&Element) -> bool>;
+
 /// Options for [`compute_text_alternative`].
 #[derive(Clone, Default)]
 pub struct ComputeTextAlternativeOptions {
@@ -34,61 +45,95 @@ pub struct ComputeTextAlternativeOptions {
     ///
     /// Defaults to `false`.
     pub hidden: Option<bool>,
+
+    /// Cache for computed styles, shared by all computations it is passed to.
+    ///
+    /// Defaults to a new cache for every computation.
+    pub computed_style_cache: Option<ComputedStyleCache>,
+
+    /// BCP 47 language tag for default names, such as the name of `<input type="submit">` without a value.
+    ///
+    /// Defaults to `en`.
+    pub locale: Option<String>,
+
+    /// Provides default names for locales missing from or overriding [`BuiltInDefaultNames`](crate::BuiltInDefaultNames).
+    pub default_name_provider: Option<Rc<dyn DefaultNameProvider>>,
+
+    /// Whether an element is a form-associated custom element, which makes it labelable.
+    ///
+    /// Custom elements defined with `static formAssociated = true` are detected without it, by looking up their
+    /// definition in `customElements` like browsers do. Elements of polyfills or other registries need this predicate.
+    pub is_form_associated: Option<IsFormAssociated>,
 }
 
+static WHITESPACE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\s\s+").expect("Regex should be valid."));
+static QUOTED_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^["'].*["']$"#).expect("Regex should be valid."));
+
 fn as_flat_string(s: String) -> String {
-    Regex::new(r"\s\s+")
-        .expect("Regex should be valid.")
-        .replace_all(&s, " ")
-        .to_string()
+    WHITESPACE_REGEX.replace_all(&s, " ").to_string()
 }
 
-fn is_hidden(node: &Node, get_computed_style_implementation: GetComputedStyle) -> bool {
-    if let Some(element) = node.dyn_ref::<Element>() {
-        if element.has_attribute("hidden")
-            || element.get_attribute("aria-hidden") == Some("true".into())
-        {
-            true
-        } else {
-            let style = get_computed_style_implementation(element, None);
-
-            style
-                .get_property_value("display")
-                .expect("Computed style should have display.")
-                == "none"
-                || style
-                    .get_property_value("visibility")
-                    .expect("Computed style should have visibility.")
-                    == "hidden"
-        }
-    } else {
-        false
-    }
+// Ancestors are not checked, because the computation has already visited them.
+fn is_hidden(node: &Node, get_computed_style_implementation: &GetComputedStyle) -> bool {
+    node.dyn_ref::<Element>().is_some_and(|element| {
+        is_subtree_inaccessible(element, get_computed_style_implementation)
+            || is_visibility_hidden(element, get_computed_style_implementation)
+    })
+}
+
+// https://w3c.github.io/accname/#comp_embedded_control
+// Buttons are included in anticipation of https://github.com/w3c/accname/issues/64.
+const EMBEDDED_CONTROL_ROLES: [AriaRole; 4] = [
+    AriaRole::Button,
+    AriaRole::Combobox,
+    AriaRole::Listbox,
+    AriaRole::Textbox,
+];
+
+fn is_control(caches: &Caches, node: &Node) -> bool {
+    has_role_or_super_role(caches, node, &EMBEDDED_CONTROL_ROLES)
+        || has_abstract_role(caches, node, AriaAbstractRole::Range)
 }
 
-fn is_control(node: &Node) -> bool {
-    has_any_concrete_roles(node, vec!["button", "combobox", "listbox", "textbox"])
-        || has_abstract_role(node, "range")
+// Each super class chain of aria-query leads up to the root role, so ancestors don't need to be resolved.
+fn super_classes(role: AriaRole) -> impl Iterator<Item = &'static AriaRoleDefinitionSuperClass> {
+    role_definition(role).super_class.iter().flatten()
 }
 
-fn has_abstract_role(node: &Node, role: &str) -> bool {
-    node.dyn_ref::<Element>().is_some_and(|element| match role {
-        "range" => has_any_concrete_roles(
-            element,
-            vec!["meter", "progressbar", "scrollbar", "slider", "spinbutton"],
-        ),
-        _ => unreachable!("No knowledge about abstract role '{role}'. This is likely a bug :("),
+/// Whether the role of the node is one of the roles or a subclass role of them, such as `searchbox` of `textbox`.
+fn has_role_or_super_role(caches: &Caches, node: &Node, roles: &[AriaRole]) -> bool {
+    caches.node_role(node).is_some_and(|role| {
+        roles.contains(&role)
+            || super_classes(role).any(|super_class| {
+                matches!(
+                    super_class,
+                    AriaRoleDefinitionSuperClass::Role(super_class) if roles.contains(super_class)
+                )
+            })
+    })
+}
+
+fn has_abstract_role(caches: &Caches, node: &Node, abstract_role: AriaAbstractRole) -> bool {
+    caches.node_role(node).is_some_and(|role| {
+        super_classes(role).any(|super_class| {
+            matches!(
+                super_class,
+                AriaRoleDefinitionSuperClass::AbstractRole(super_class) if *super_class == abstract_role
+            )
+        })
     })
 }
 
-fn query_selector_all_subtree(element: &Element, selectors: &str) -> Vec<Element> {
+fn query_selector_all_subtree(caches: &Caches, element: &Element, selectors: &str) -> Vec<Element> {
     let mut elements = node_list_to_vec(
         element
             .query_selector_all(selectors)
             .expect("Element should be queried."),
     );
 
-    for root in query_id_refs(element, "aria-owns") {
+    for root in caches.query_id_refs(element, "aria-owns") {
         elements.extend(node_list_to_vec(
             root.query_selector_all(selectors)
                 .expect("Element should be queried."),
@@ -98,57 +143,38 @@ fn query_selector_all_subtree(element: &Element, selectors: &str) -> Vec<Element
     elements
 }
 
-fn query_selected_options(listbox: &Element) -> Vec<Element> {
+fn query_selected_options(caches: &Caches, listbox: &Element) -> Vec<Element> {
     if let Some(select_element) = listbox.dyn_ref::<HtmlSelectElement>() {
         html_collection_to_vec(select_element.selected_options())
     } else {
-        query_selector_all_subtree(listbox, "[aria-selected=\"true\"]")
+        query_selector_all_subtree(caches, listbox, "[aria-selected=\"true\"]")
     }
 }
 
-fn is_marked_presentational(node: &Node) -> bool {
-    has_any_concrete_roles(node, PRESENTATION_ROLES.into())
+fn is_marked_presentational(caches: &Caches, node: &Node) -> bool {
+    has_any_concrete_roles(caches, node, PRESENTATION_ROLES.into())
 }
 
 fn is_native_host_language_text_alternative_element(node: &Node) -> bool {
     // Elements specifically listed in html-aam.
-    // We don't need this for `label` or `legend` elements. Their implicit roles already allow "naming from content".
     //
     // https://w3c.github.io/html-aam/#table-element
+    // https://w3c.github.io/html-aam/#fieldset-and-legend-elements
+    // https://w3c.github.io/html-aam/#figure-and-figcaption-elements
+    // https://w3c.github.io/html-aam/#label-element
     node.is_instance_of::<HtmlTableCaptionElement>()
+        || node.is_instance_of::<HtmlLegendElement>()
+        || node.is_instance_of::<HtmlLabelElement>()
+        || node
+            .dyn_ref::<Element>()
+            .is_some_and(|element| element.local_name() == "figcaption")
 }
 
-fn allows_name_from_content(node: &Node) -> bool {
-    has_any_concrete_roles(
-        node,
-        vec![
-            "button",
-            "cell",
-            "checkbox",
-            "columnheader",
-            "gridcell",
-            "heading",
-            "label",
-            "legend",
-            "link",
-            "menuitem",
-            "menuitemcheckbox",
-            "menuitemradio",
-            "option",
-            "radio",
-            "row",
-            "rowheader",
-            "switch",
-            "tab",
-            "tooltip",
-            "treeitem",
-        ],
-    )
-}
-
-// TODO: https://github.com/eps1lon/dom-accessibility-api/issues/100
-fn is_descendant_of_native_host_language_text_alternative_element(_node: &Node) -> bool {
-    false
+// https://w3c.github.io/aria/#namefromcontent
+fn allows_name_from_content(caches: &Caches, node: &Node) -> bool {
+    caches
+        .node_role(node)
+        .is_some_and(|role| name_from(role).contains(&AriaNameFromSource::Contents))
 }
 
 fn get_value_of_textbox(element: &Element) -> String {
@@ -166,10 +192,7 @@ fn get_textual_content(declaration: CssStyleDeclaration) -> String {
     let content = declaration
         .get_property_value("content")
         .expect("CssStyleDeclaration should have content.");
-    if Regex::new(r#"^["'].*["']$"#)
-        .expect("Regex should be valid.")
-        .is_match(&content)
-    {
+    if QUOTED_REGEX.is_match(&content) {
         (&content[1..content.len() - 1]).into()
     } else {
         "".into()
@@ -177,8 +200,7 @@ fn get_textual_content(declaration: CssStyleDeclaration) -> String {
 }
 
 // https://html.spec.whatwg.org/multipage/forms.html#category-label
-// TODO: form-associated custom elements
-fn is_labelable_element(element: &Element) -> bool {
+fn is_labelable_element(caches: &Caches, element: &Element) -> bool {
     let local_name = element.local_name();
 
     local_name == "button"
@@ -188,18 +210,19 @@ fn is_labelable_element(element: &Element) -> bool {
         || local_name == "progress"
         || local_name == "select"
         || local_name == "textarea"
+        || is_form_associated_custom_element(element, caches.is_form_associated.as_ref())
 }
 
 // > [...], then the first such descendant in tree order is the label element's labeled control.
 // https://html.spec.whatwg.org/multipage/forms.html#labeled-control
-fn find_labelable_element(element: &Element) -> Option<Element> {
-    if is_labelable_element(element) {
+fn find_labelable_element(caches: &Caches, element: &Element) -> Option<Element> {
+    if is_labelable_element(caches, element) {
         return Some(element.clone());
     }
 
     for child_node in node_list_to_vec::<Node>(element.child_nodes()) {
         if let Some(child_element) = child_node.dyn_ref::<Element>() {
-            let descendant_labelable_element = find_labelable_element(child_element);
+            let descendant_labelable_element = find_labelable_element(caches, child_element);
             if let Some(descendant_labelable_element) = descendant_labelable_element {
                 return Some(descendant_labelable_element);
             }
@@ -211,7 +234,7 @@ fn find_labelable_element(element: &Element) -> Option<Element> {
 
 // Polyfill of HTMLLabelElement.control
 // https://html.spec.whatwg.org/multipage/forms.html#labeled-control
-fn get_control_of_label(label: &HtmlLabelElement) -> Option<Element> {
+pub fn get_control_of_label(caches: &Caches, label: &HtmlLabelElement) -> Option<Element> {
     if let Some(control) = label.control() {
         return Some(control.into());
     }
@@ -224,31 +247,21 @@ fn get_control_of_label(label: &HtmlLabelElement) -> Option<Element> {
             .get_element_by_id(&html_for);
     }
 
-    find_labelable_element(label)
+    find_labelable_element(caches, label)
 }
 
 // Polyfill of HTMLInputElement.labels
 // https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/labels
-fn get_labels(element: &Element) -> Vec<HtmlLabelElement> {
+fn get_labels(caches: &Caches, element: &Element) -> Vec<HtmlLabelElement> {
     if let Some(input_element) = element.dyn_ref::<HtmlInputElement>() {
         input_element
             .labels()
             .map(node_list_to_vec)
             .unwrap_or_default()
-    } else if !is_labelable_element(element) {
+    } else if !is_labelable_element(caches, element) {
         vec![]
     } else {
-        let document = element
-            .owner_document()
-            .expect("Owner document should exist.");
-        node_list_to_vec(
-            document
-                .query_selector_all("label")
-                .expect("Document should be queried."),
-        )
-        .into_iter()
-        .filter(|label| get_control_of_label(label).is_some_and(|label| label == *element))
-        .collect()
+        caches.labels(element)
     }
 }
 
@@ -268,51 +281,34 @@ fn get_slot_contents(slot: &HtmlSlotElement) -> Vec<Node> {
 
 struct ComputeTextAlternativeContext {
     is_embedded_in_label: bool,
+    /// Whether a native host language text alternative element, such as a label, is being traversed.
+    is_in_text_alternative: bool,
     is_referenced: bool,
     recursion: bool,
 }
 
 struct ComputeMiscTextAlternativeContext {
     is_embedded_in_label: bool,
+    is_in_text_alternative: bool,
     #[expect(dead_code)]
     is_referenced: bool,
 }
 
 // Implements <https://w3c.github.io/accname/#mapping_additional_nd_te>.
-pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeOptions) -> String {
-    let mut consulted_nodes: Vec<Node> = vec![];
-
+pub fn compute_text_alternative(
+    root: &Element,
+    options: ComputeTextAlternativeOptions,
+    caches: &Caches,
+    consulted_nodes: &mut Vec<Node>,
+) -> String {
     let compute = options.compute.unwrap_or(Compute::Name);
-    let uncached_get_computed_style = options.get_computed_style.unwrap_or_else(|| {
-        Rc::new(|element, pseudo_elt| {
-            let window = window().expect("Window should exist.");
-
-            if let Some(pseudo_elt) = pseudo_elt {
-                window.get_computed_style_with_pseudo_elt(element, pseudo_elt)
-            } else {
-                window.get_computed_style(element)
-            }
-            .expect("Element should be valid.")
-            .expect("Computed style should exist.")
-        })
-    });
     let hidden = options.hidden.unwrap_or(false);
 
-    let get_computed_style: GetComputedStyle = Rc::new({
-        let uncached_get_computed_style = uncached_get_computed_style.clone();
-
-        move |element, pseudo_elt| {
-            // TODO: cache
-            uncached_get_computed_style(element, pseudo_elt)
-        }
-    });
-
     // 2F.i
     fn compute_misc_text_alternative(
         compute: Compute,
         hidden: bool,
-        uncached_get_computed_style: GetComputedStyle,
-        get_computed_style: GetComputedStyle,
+        caches: &Caches,
         consulted_nodes: &mut Vec<Node>,
         node: &Node,
         context: ComputeMiscTextAlternativeContext,
@@ -320,7 +316,7 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
         let mut accumalated_text = "".to_string();
 
         if let Some(element) = node.dyn_ref::<Element>() {
-            let pseudo_before = uncached_get_computed_style(element, Some("::before"));
+            let pseudo_before = (caches.get_computed_style)(element, Some("::before"));
             let before_content = get_textual_content(pseudo_before);
             accumalated_text = format!("{before_content} {accumalated_text}");
         }
@@ -332,7 +328,8 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
             .unwrap_or_else(|| {
                 let mut nodes = node_list_to_vec(node.child_nodes());
                 nodes.extend(
-                    query_id_refs(node, "aria-owns")
+                    caches
+                        .query_id_refs(node, "aria-owns")
                         .into_iter()
                         .map(|element| element.into()),
                 );
@@ -342,19 +339,20 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
             let result = inner_compute_text_alternative(
                 compute,
                 hidden,
-                uncached_get_computed_style.clone(),
-                get_computed_style.clone(),
+                caches,
                 consulted_nodes,
                 &child,
                 ComputeTextAlternativeContext {
                     is_embedded_in_label: context.is_embedded_in_label,
+                    is_in_text_alternative: context.is_in_text_alternative
+                        || is_native_host_language_text_alternative_element(node),
                     is_referenced: false,
                     recursion: true,
                 },
             );
             // TODO: Unclear why display affects delimiter, see https://github.com/w3c/accname/issues/3.
             let display = if let Some(element) = child.dyn_ref::<Element>() {
-                get_computed_style(element, None)
+                (caches.get_computed_style)(element, None)
                     .get_property_value("display")
                     .expect("Computed style should have display.")
             } else {
@@ -366,7 +364,7 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
         }
 
         if let Some(element) = node.dyn_ref::<Element>() {
-            let pseudo_after = uncached_get_computed_style(element, Some("::after"));
+            let pseudo_after = (caches.get_computed_style)(element, Some("::after"));
             let after_content = get_textual_content(pseudo_after);
             accumalated_text = format!("{accumalated_text} {after_content}");
         }
@@ -401,8 +399,7 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
     fn compute_element_text_alternative(
         compute: Compute,
         hidden: bool,
-        uncached_get_computed_style: GetComputedStyle,
-        get_computed_style: GetComputedStyle,
+        caches: &Caches,
         consulted_nodes: &mut Vec<Node>,
         node: &Node,
     ) -> Option<String> {
@@ -420,12 +417,12 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
                         return Some(inner_compute_text_alternative(
                             compute,
                             hidden,
-                            uncached_get_computed_style,
-                            get_computed_style,
+                            caches,
                             consulted_nodes,
                             &child,
                             ComputeTextAlternativeContext {
                                 is_embedded_in_label: false,
+                                is_in_text_alternative: false,
                                 is_referenced: false,
                                 recursion: false,
                             },
@@ -445,12 +442,12 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
                         return Some(inner_compute_text_alternative(
                             compute,
                             hidden,
-                            uncached_get_computed_style,
-                            get_computed_style,
+                            caches,
                             consulted_nodes,
                             &child,
                             ComputeTextAlternativeContext {
                                 is_embedded_in_label: false,
+                                is_in_text_alternative: false,
                                 is_referenced: false,
                                 recursion: false,
                             },
@@ -492,17 +489,15 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
                     return Some(name_from_value);
                 }
 
-                // TODO: l10n
                 if input_element.type_() == "submit" {
-                    return Some("Submit".into());
+                    return Some(caches.default_names.submit.clone());
                 }
-                // TODO: l10n
                 if input_element.type_() == "reset" {
-                    return Some("Reset".into());
+                    return Some(caches.default_names.reset.clone());
                 }
             }
 
-            let labels = get_labels(element);
+            let labels = get_labels(caches, element);
             if !labels.is_empty() {
                 consulted_nodes.push(node.clone());
 
@@ -513,12 +508,12 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
                             inner_compute_text_alternative(
                                 compute,
                                 hidden,
-                                uncached_get_computed_style.clone(),
-                                get_computed_style.clone(),
+                                caches,
                                 consulted_nodes,
                                 &element,
                                 ComputeTextAlternativeContext {
                                     is_embedded_in_label: true,
+                                    is_in_text_alternative: false,
                                     is_referenced: false,
                                     recursion: true,
                                 },
@@ -546,21 +541,20 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
                     return Some(name_for_alt);
                 }
 
-                // TODO: l10n
-                return Some("Submit Query".into());
+                return Some(caches.default_names.submit_query.clone());
             }
 
-            if has_any_concrete_roles(node, vec!["button"]) {
+            if has_any_concrete_roles(caches, node, vec![AriaRole::Button]) {
                 // https://www.w3.org/TR/html-aam-1.0/#button-element
                 let name_from_sub_tree = compute_misc_text_alternative(
                     compute,
                     hidden,
-                    uncached_get_computed_style,
-                    get_computed_style,
+                    caches,
                     consulted_nodes,
                     node,
                     ComputeMiscTextAlternativeContext {
                         is_embedded_in_label: false,
+                        is_in_text_alternative: false,
                         is_referenced: false,
                     },
                 );
@@ -576,8 +570,7 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
     fn inner_compute_text_alternative(
         compute: Compute,
         hidden: bool,
-        uncached_get_computed_style: GetComputedStyle,
-        get_computed_style: GetComputedStyle,
+        caches: &Caches,
         consulted_nodes: &mut Vec<Node>,
         current: &Node,
         context: ComputeTextAlternativeContext,
@@ -587,7 +580,7 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
         }
 
         // 2A
-        if !hidden && is_hidden(current, get_computed_style.clone()) && !context.is_referenced {
+        if !hidden && is_hidden(current, &caches.get_computed_style) && !context.is_referenced {
             consulted_nodes.push(current.clone());
             return "".into();
         }
@@ -598,7 +591,7 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
         {
             // TODO: Do we generally need to block query IdRefs of attributes we have already consulted?
             let label_elements = if !consulted_nodes.contains(&label_attribute_node) {
-                query_id_refs(current, "aria-labelledby")
+                caches.query_id_refs(current, "aria-labelledby")
             } else {
                 vec![]
             };
@@ -613,12 +606,12 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
                         inner_compute_text_alternative(
                             compute,
                             hidden,
-                            uncached_get_computed_style.clone(),
-                            get_computed_style.clone(),
+                            caches,
                             consulted_nodes,
                             &element,
                             ComputeTextAlternativeContext {
                                 is_embedded_in_label: context.is_embedded_in_label,
+                                is_in_text_alternative: context.is_in_text_alternative,
                                 is_referenced: true,
                                 // This isn't recursion as specified, otherwise we would skip `aria-label` in
                                 // <input id="myself" aria-label="foo" aria-labelledby="myself" />
@@ -634,7 +627,8 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
         // 2C
         // Changed from the spec in anticipation of https://github.com/w3c/accname/issues/64.
         // Spec says we should only consider skipping if we have a non-empty label.
-        let skip_to_step_2e = context.recursion && is_control(current) && compute == Compute::Name;
+        let skip_to_step_2e =
+            context.recursion && is_control(caches, current) && compute == Compute::Name;
         if !skip_to_step_2e {
             let aria_label = current
                 .dyn_ref::<Element>()
@@ -648,12 +642,11 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
             }
 
             // 2D
-            if !is_marked_presentational(current)
+            if !is_marked_presentational(caches, current)
                 && let Some(element_text_alternative) = compute_element_text_alternative(
                     compute,
                     hidden,
-                    uncached_get_computed_style.clone(),
-                    get_computed_style.clone(),
+                    caches,
                     consulted_nodes,
                     current,
                 )
@@ -665,17 +658,18 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
 
         // Special casing, cheating to make tests pass.
         // https://github.com/w3c/accname/issues/67
-        if has_any_concrete_roles(current, vec!["menu"]) {
+        if has_any_concrete_roles(caches, current, vec![AriaRole::Menu]) {
             consulted_nodes.push(current.clone());
             return "".into();
         }
 
         // 2E
         if skip_to_step_2e || context.is_embedded_in_label || context.is_referenced {
-            if has_any_concrete_roles(current, vec!["combobox", "listbox"]) {
+            if has_role_or_super_role(caches, current, &[AriaRole::Combobox, AriaRole::Listbox]) {
                 consulted_nodes.push(current.clone());
 
                 let selected_options = query_selected_options(
+                    caches,
                     current
                         .dyn_ref::<Element>()
                         .expect("Node should be an Element."),
@@ -693,12 +687,12 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
                         inner_compute_text_alternative(
                             compute,
                             hidden,
-                            uncached_get_computed_style.clone(),
-                            get_computed_style.clone(),
+                            caches,
                             consulted_nodes,
                             selected_option,
                             ComputeTextAlternativeContext {
                                 is_embedded_in_label: context.is_embedded_in_label,
+                                is_in_text_alternative: context.is_in_text_alternative,
                                 is_referenced: false,
                                 recursion: true,
                             },
@@ -707,7 +701,7 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
                     .collect::<Vec<_>>()
                     .join(" ");
             }
-            if has_abstract_role(current, "range") {
+            if has_abstract_role(caches, current, AriaAbstractRole::Range) {
                 consulted_nodes.push(current.clone());
                 let element = current
                     .dyn_ref::<Element>()
@@ -724,7 +718,7 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
                 }
                 return element.get_attribute("value").unwrap_or("".into());
             }
-            if has_any_concrete_roles(current, vec!["textbox"]) {
+            if has_role_or_super_role(caches, current, &[AriaRole::Textbox]) {
                 consulted_nodes.push(current.clone());
 
                 return get_value_of_textbox(
@@ -736,20 +730,21 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
         }
 
         // 2F
-        if allows_name_from_content(current)
+        if allows_name_from_content(caches, current)
             || (current.is_instance_of::<Element>() && context.is_referenced)
             || is_native_host_language_text_alternative_element(current)
-            || is_descendant_of_native_host_language_text_alternative_element(current)
+            // https://github.com/eps1lon/dom-accessibility-api/issues/100
+            || context.is_in_text_alternative
         {
             let accumulated_text_2f = compute_misc_text_alternative(
                 compute,
                 hidden,
-                uncached_get_computed_style.clone(),
-                get_computed_style.clone(),
+                caches,
                 consulted_nodes,
                 current,
                 ComputeMiscTextAlternativeContext {
                     is_embedded_in_label: context.is_embedded_in_label,
+                    is_in_text_alternative: context.is_in_text_alternative,
                     is_referenced: false,
                 },
             );
@@ -769,12 +764,12 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
             return compute_misc_text_alternative(
                 compute,
                 hidden,
-                uncached_get_computed_style,
-                get_computed_style,
+                caches,
                 consulted_nodes,
                 current,
                 ComputeMiscTextAlternativeContext {
                     is_embedded_in_label: context.is_embedded_in_label,
+                    is_in_text_alternative: context.is_in_text_alternative,
                     is_referenced: false,
                 },
             );
@@ -794,15 +789,47 @@ pub fn compute_text_alternative(root: &Element, options: ComputeTextAlternativeO
     as_flat_string(inner_compute_text_alternative(
         compute,
         hidden,
-        uncached_get_computed_style,
-        get_computed_style,
-        &mut consulted_nodes,
+        caches,
+        consulted_nodes,
         root,
         ComputeTextAlternativeContext {
             is_embedded_in_label: false,
+            is_in_text_alternative: false,
             // By spec `compute_accessible_description starts with the referenced elements as roots.
             is_referenced: compute == Compute::Description,
             recursion: false,
         },
     ))
 }
+
+/// Accessible name and description of an element, see [`compute_accessible_name_and_description`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AccessibleNameAndDescription {
+    pub name: String,
+    pub description: String,
+}
+
+pub fn name_and_description_with_caches(
+    root: &Element,
+    options: ComputeTextAlternativeOptions,
+    caches: &Caches,
+) -> AccessibleNameAndDescription {
+    let mut consulted_nodes = vec![];
+
+    AccessibleNameAndDescription {
+        name: name_with_caches(root, options.clone(), caches, &mut consulted_nodes),
+        description: description_with_caches(root, options, caches, &consulted_nodes),
+    }
+}
+
+/// Computes the accessible name and description in one pass.
+///
+/// Nodes consulted for the name, such as the `title` attribute, are not used again for the description.
+pub fn compute_accessible_name_and_description(
+    root: &Element,
+    options: ComputeTextAlternativeOptions,
+) -> AccessibleNameAndDescription {
+    let caches = Caches::new(&options);
+
+    name_and_description_with_caches(root, options, &caches)
+}
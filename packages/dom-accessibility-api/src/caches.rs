@@ -0,0 +1,186 @@
+use std::{cell::RefCell, rc::Rc};
+
+use aria_query::AriaRole;
+use web_sys::{
+    Document, DocumentFragment, Element, HtmlLabelElement, Node,
+    js_sys::{Array, Map, WeakMap},
+    wasm_bindgen::{JsCast, JsValue},
+};
+
+use crate::{
+    accessible_name_and_description::{
+        ComputeTextAlternativeOptions, GetComputedStyle, IsFormAssociated, get_control_of_label,
+    },
+    computed_style_cache::ComputedStyleCache,
+    get_role::get_aria_role,
+    locale::DefaultNames,
+    util::{array_to_vec, default_get_computed_style, node_list_to_vec},
+};
+
+/// Caches for the computation of names, descriptions and roles.
+///
+/// Every computation uses new caches by default, which assumes the DOM does not change during a computation.
+/// [`ComputeSession`](crate::ComputeSession) shares them between computations.
+#[derive(Clone)]
+pub struct Caches {
+    computed_style_cache: ComputedStyleCache,
+    pub get_computed_style: GetComputedStyle,
+    pub default_names: DefaultNames,
+    pub is_form_associated: Option<IsFormAssociated>,
+
+    // `Element` does not implement `Hash`, so weak maps keyed by identity are used instead.
+    /// Labels per control, per document.
+    labels: Rc<RefCell<WeakMap>>,
+    /// Elements per ID, per document or document fragment.
+    ids: Rc<RefCell<WeakMap>>,
+    /// Roles per element.
+    roles: Rc<RefCell<WeakMap>>,
+}
+
+impl Caches {
+    pub fn new(options: &ComputeTextAlternativeOptions) -> Self {
+        let computed_style_cache = options.computed_style_cache.clone().unwrap_or_default();
+
+        Caches {
+            get_computed_style: computed_style_cache.wrap(
+                options
+                    .get_computed_style
+                    .clone()
+                    .unwrap_or_else(default_get_computed_style),
+            ),
+            computed_style_cache,
+            default_names: DefaultNames::new(
+                options.locale.as_deref(),
+                options.default_name_provider.as_ref(),
+            ),
+            is_form_associated: options.is_form_associated.clone(),
+            labels: Rc::new(RefCell::new(WeakMap::new())),
+            ids: Rc::new(RefCell::new(WeakMap::new())),
+            roles: Rc::new(RefCell::new(WeakMap::new())),
+        }
+    }
+
+    /// Remove all cached values.
+    pub fn invalidate(&self) {
+        self.computed_style_cache.clear();
+        self.labels.replace(WeakMap::new());
+        self.ids.replace(WeakMap::new());
+        self.roles.replace(WeakMap::new());
+    }
+
+    fn element_by_id(&self, root: &Node, element_id: &str) -> Option<Element> {
+        let ids = self.ids.borrow().clone();
+
+        let root_ids = match ids.get(root).dyn_into::<Map>() {
+            Ok(root_ids) => root_ids,
+            Err(_) => {
+                let root_ids = Map::new();
+                ids.set(root, &root_ids);
+                root_ids
+            }
+        };
+
+        let key = JsValue::from_str(element_id);
+        if root_ids.has(&key) {
+            return root_ids.get(&key).dyn_into::<Element>().ok();
+        }
+
+        let element = if let Some(document) = root.dyn_ref::<Document>() {
+            document.get_element_by_id(element_id)
+        } else if let Some(document_fragment) = root.dyn_ref::<DocumentFragment>() {
+            document_fragment.get_element_by_id(element_id)
+        } else {
+            unreachable!("Node should be Document or DocumentFragment.")
+        };
+        root_ids.set(
+            &key,
+            &element.clone().map(JsValue::from).unwrap_or(JsValue::NULL),
+        );
+
+        element
+    }
+
+    pub fn query_id_refs(&self, node: &Node, attribute_name: &str) -> Vec<Element> {
+        if let Some(element) = node.dyn_ref::<Element>()
+            && let Some(attribute_value) = element.get_attribute(attribute_name)
+        {
+            let root = element.get_root_node();
+
+            attribute_value
+                .split(' ')
+                .filter_map(|element_id| self.element_by_id(&root, element_id))
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    /// Labels of the control, indexing all labels of its document on the first call.
+    pub fn labels(&self, control: &Element) -> Vec<HtmlLabelElement> {
+        let Some(document) = control.owner_document() else {
+            return vec![];
+        };
+        let labels = self.labels.borrow().clone();
+
+        let document_labels = match labels.get(&document).dyn_into::<Map>() {
+            Ok(document_labels) => document_labels,
+            Err(_) => {
+                let document_labels = Map::new();
+
+                for label in node_list_to_vec::<HtmlLabelElement>(
+                    document
+                        .query_selector_all("label")
+                        .expect("Document should be queried."),
+                ) {
+                    if let Some(labeled_control) = get_control_of_label(self, &label) {
+                        let control_labels =
+                            match document_labels.get(&labeled_control).dyn_into::<Array>() {
+                                Ok(control_labels) => control_labels,
+                                Err(_) => {
+                                    let control_labels = Array::new();
+                                    document_labels.set(&labeled_control, &control_labels);
+                                    control_labels
+                                }
+                            };
+                        control_labels.push(&label);
+                    }
+                }
+
+                labels.set(&document, &document_labels);
+                document_labels
+            }
+        };
+
+        document_labels
+            .get(control)
+            .dyn_into::<Array>()
+            .map(array_to_vec)
+            .unwrap_or_default()
+    }
+
+    /// Role of the node, if it is an element.
+    pub fn node_role(&self, node: &Node) -> Option<AriaRole> {
+        node.dyn_ref::<Element>()
+            .and_then(|element| self.role(element))
+    }
+
+    pub fn role(&self, element: &Element) -> Option<AriaRole> {
+        let roles = self.roles.borrow().clone();
+
+        let cached_role = roles.get(element);
+        if !cached_role.is_undefined() {
+            return cached_role
+                .as_string()
+                .map(|role| role.parse().expect("Cached role should be valid."));
+        }
+
+        let role = get_aria_role(element);
+        roles.set(
+            element,
+            &role
+                .map(|role| JsValue::from_str(&role.to_string()))
+                .unwrap_or(JsValue::NULL),
+        );
+        role
+    }
+}
@@ -0,0 +1,132 @@
+use std::rc::Rc;
+
+use aria_query::AriaRole;
+use web_sys::{
+    Element, MutationObserver, MutationObserverInit, Node,
+    wasm_bindgen::{JsCast, closure::Closure},
+};
+
+use crate::{
+    accessible_description::description_with_caches,
+    accessible_name::name_with_caches,
+    accessible_name_and_description::{
+        AccessibleNameAndDescription, ComputeTextAlternativeOptions,
+        name_and_description_with_caches,
+    },
+    caches::Caches,
+    get_role::role_string,
+};
+
+/// Invalidates the caches of a session when the observed DOM changes, until the session is dropped.
+struct SessionObserver {
+    observer: MutationObserver,
+    _callback: Closure<dyn Fn()>,
+}
+
+impl SessionObserver {
+    fn new(caches: &Caches) -> Self {
+        let callback = Closure::<dyn Fn()>::new({
+            let caches = caches.clone();
+
+            move || caches.invalidate()
+        });
+        let observer = MutationObserver::new(callback.as_ref().unchecked_ref())
+            .expect("MutationObserver should be created.");
+
+        SessionObserver {
+            observer,
+            _callback: callback,
+        }
+    }
+
+    /// Whether mutations happened since the last callback, which runs asynchronously.
+    fn take_records(&self) -> bool {
+        self.observer.take_records().length() > 0
+    }
+}
+
+impl Drop for SessionObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// Computes names, descriptions and roles of many elements, sharing caches between the computations.
+///
+/// The caches hold computed styles, labels per control, elements per ID and roles. They assume the DOM does not
+/// change, so either [`observe`](ComputeSession::observe) the DOM to invalidate them on changes automatically, or
+/// call [`invalidate`](ComputeSession::invalidate) after changing the DOM. Changes to style sheets are not observed.
+#[derive(Clone)]
+pub struct ComputeSession {
+    options: ComputeTextAlternativeOptions,
+    caches: Caches,
+    observer: Rc<SessionObserver>,
+}
+
+impl ComputeSession {
+    pub fn new(options: ComputeTextAlternativeOptions) -> Self {
+        let caches = Caches::new(&options);
+
+        ComputeSession {
+            observer: Rc::new(SessionObserver::new(&caches)),
+            caches,
+            options,
+        }
+    }
+
+    /// Invalidate the caches whenever elements or attributes inside the root change, e.g. the document.
+    ///
+    /// Observes until the last clone of the session is dropped.
+    pub fn observe(&self, root: &Node) {
+        let init = MutationObserverInit::new();
+        init.set_attributes(true);
+        init.set_child_list(true);
+        init.set_subtree(true);
+
+        self.observer
+            .observer
+            .observe_with_options(root, &init)
+            .expect("Root should be observed.");
+    }
+
+    /// Invalidate the caches if mutations happened which the observer callback has not handled yet.
+    fn sync(&self) {
+        if self.observer.take_records() {
+            self.caches.invalidate();
+        }
+    }
+
+    /// See [`compute_accessible_name`](crate::compute_accessible_name).
+    pub fn name(&self, element: &Element) -> String {
+        self.sync();
+        name_with_caches(element, self.options.clone(), &self.caches, &mut vec![])
+    }
+
+    /// See [`compute_accessible_description`](crate::compute_accessible_description).
+    pub fn description(&self, element: &Element) -> String {
+        self.sync();
+        description_with_caches(element, self.options.clone(), &self.caches, &[])
+    }
+
+    /// See [`compute_accessible_name_and_description`](crate::compute_accessible_name_and_description).
+    pub fn name_and_description(&self, element: &Element) -> AccessibleNameAndDescription {
+        self.sync();
+        name_and_description_with_caches(element, self.options.clone(), &self.caches)
+    }
+
+    /// See [`get_role`](crate::get_role).
+    pub fn role(&self, element: &Element) -> Option<String> {
+        role_string(element, self.aria_role(element))
+    }
+
+    /// See [`get_aria_role`](crate::get_aria_role).
+    pub fn aria_role(&self, element: &Element) -> Option<AriaRole> {
+        self.sync();
+        self.caches.role(element)
+    }
+
+    /// Remove all cached values, after the DOM or styles changed.
+    pub fn invalidate(&self) {
+        self.caches.invalidate();
+    }
+}
@@ -0,0 +1,57 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use web_sys::{CssStyleDeclaration, Element, js_sys::WeakMap, wasm_bindgen::JsCast};
+
+use crate::accessible_name_and_description::GetComputedStyle;
+
+/// Computed styles keyed by element and pseudo-element.
+///
+/// Every computation uses a new cache by default. Pass the same cache to multiple computations to share it and
+/// [`clear`](ComputedStyleCache::clear) it whenever the DOM or styles change.
+#[derive(Clone, Default)]
+pub struct ComputedStyleCache {
+    // `Element` does not implement `Hash`, so a weak map keyed by element identity is used per pseudo-element.
+    styles: Rc<RefCell<HashMap<Option<String>, WeakMap>>>,
+}
+
+impl ComputedStyleCache {
+    pub fn new() -> Self {
+        ComputedStyleCache::default()
+    }
+
+    /// Get the cached style, calling `get_computed_style` on a miss.
+    pub fn get(
+        &self,
+        get_computed_style: &GetComputedStyle,
+        element: &Element,
+        pseudo_elt: Option<&str>,
+    ) -> CssStyleDeclaration {
+        let styles = self
+            .styles
+            .borrow_mut()
+            .entry(pseudo_elt.map(String::from))
+            .or_default()
+            .clone();
+
+        let cached_style = styles.get(element);
+        if !cached_style.is_undefined() {
+            return cached_style.unchecked_into();
+        }
+
+        let style = get_computed_style(element, pseudo_elt);
+        styles.set(element, &style);
+        style
+    }
+
+    /// Wrap `get_computed_style`, so every call goes through this cache.
+    pub fn wrap(&self, get_computed_style: GetComputedStyle) -> GetComputedStyle {
+        let cache = self.clone();
+
+        Rc::new(move |element, pseudo_elt| cache.get(&get_computed_style, element, pseudo_elt))
+    }
+
+    /// Remove all cached styles.
+    pub fn clear(&self) {
+        self.styles.borrow_mut().clear();
+    }
+}
@@ -1,60 +1,79 @@
 use std::{collections::HashMap, sync::LazyLock};
 
+use aria_query::AriaRole;
 use web_sys::{Element, HtmlInputElement, HtmlSelectElement, wasm_bindgen::JsCast};
 
-use crate::util::PRESENTATION_ROLES;
+use crate::util::{PRESENTATION_ROLES, html_collection_to_vec};
 
-// https://w3c.github.io/html-aria/#document-conformance-requirements-for-use-of-aria-attributes-in-html
-
-static LOCAL_NAME_TO_ROLE_MAPPINGS: LazyLock<HashMap<String, String>> = LazyLock::new(|| {
+// https://w3c.github.io/html-aam/#html-element-role-mappings
+// Elements depending on their context or attributes are handled by `get_implicit_role`.
+static LOCAL_NAME_TO_ROLE_MAPPINGS: LazyLock<HashMap<String, AriaRole>> = LazyLock::new(|| {
     HashMap::from([
-        ("article".into(), "article".into()),
-        ("aside".into(), "complementary".into()),
-        ("button".into(), "button".into()),
-        ("datalist".into(), "listbox".into()),
-        ("dd".into(), "definition".into()),
-        ("details".into(), "group".into()),
-        ("dialog".into(), "dialog".into()),
-        ("dt".into(), "term".into()),
-        ("fieldset".into(), "group".into()),
-        ("figure".into(), "figure".into()),
-        // Warning: Only with an accessible name.
-        ("form".into(), "form".into()),
-        ("footer".into(), "contentinfo".into()),
-        ("h1".into(), "heading".into()),
-        ("h2".into(), "heading".into()),
-        ("h3".into(), "heading".into()),
-        ("h4".into(), "heading".into()),
-        ("h5".into(), "heading".into()),
-        ("h6".into(), "heading".into()),
-        ("header".into(), "banner".into()),
-        ("hr".into(), "separator".into()),
-        ("html".into(), "document".into()),
-        ("legend".into(), "legend".into()),
-        ("li".into(), "listitem".into()),
-        ("math".into(), "math".into()),
-        ("main".into(), "main".into()),
-        ("menu".into(), "list".into()),
-        ("nav".into(), "navigation".into()),
-        ("ol".into(), "list".into()),
-        ("optgroup".into(), "group".into()),
-        // Warning: Only in certain context.
-        ("option".into(), "option".into()),
-        ("output".into(), "status".into()),
-        ("progress".into(), "progressbar".into()),
-        // Warning: Only with an accessible name.
-        ("section".into(), "region".into()),
-        ("summary".into(), "button".into()),
-        ("table".into(), "table".into()),
-        ("tbody".into(), "rowgroup".into()),
-        ("textarea".into(), "textbox".into()),
-        ("tfoot".into(), "rowgroup".into()),
-        // Warning: Only in certain context.
-        ("td".into(), "cell".into()),
-        ("th".into(), "columnheader".into()),
-        ("thead".into(), "rowgroup".into()),
-        ("tr".into(), "row".into()),
-        ("ul".into(), "list".into()),
+        ("address".into(), AriaRole::Group),
+        ("article".into(), AriaRole::Article),
+        ("b".into(), AriaRole::Generic),
+        ("bdi".into(), AriaRole::Generic),
+        ("bdo".into(), AriaRole::Generic),
+        ("blockquote".into(), AriaRole::Blockquote),
+        ("body".into(), AriaRole::Generic),
+        ("button".into(), AriaRole::Button),
+        ("caption".into(), AriaRole::Caption),
+        ("code".into(), AriaRole::Code),
+        ("data".into(), AriaRole::Generic),
+        ("datalist".into(), AriaRole::Listbox),
+        ("dd".into(), AriaRole::Definition),
+        ("del".into(), AriaRole::Deletion),
+        ("details".into(), AriaRole::Group),
+        ("dfn".into(), AriaRole::Term),
+        ("dialog".into(), AriaRole::Dialog),
+        ("div".into(), AriaRole::Generic),
+        ("dt".into(), AriaRole::Term),
+        ("em".into(), AriaRole::Emphasis),
+        ("fieldset".into(), AriaRole::Group),
+        ("figure".into(), AriaRole::Figure),
+        ("h1".into(), AriaRole::Heading),
+        ("h2".into(), AriaRole::Heading),
+        ("h3".into(), AriaRole::Heading),
+        ("h4".into(), AriaRole::Heading),
+        ("h5".into(), AriaRole::Heading),
+        ("h6".into(), AriaRole::Heading),
+        ("hgroup".into(), AriaRole::Group),
+        ("hr".into(), AriaRole::Separator),
+        ("html".into(), AriaRole::Document),
+        ("i".into(), AriaRole::Generic),
+        ("ins".into(), AriaRole::Insertion),
+        ("main".into(), AriaRole::Main),
+        ("mark".into(), AriaRole::Mark),
+        ("math".into(), AriaRole::Math),
+        ("menu".into(), AriaRole::List),
+        ("meter".into(), AriaRole::Meter),
+        ("nav".into(), AriaRole::Navigation),
+        ("ol".into(), AriaRole::List),
+        ("optgroup".into(), AriaRole::Group),
+        ("output".into(), AriaRole::Status),
+        ("p".into(), AriaRole::Paragraph),
+        ("pre".into(), AriaRole::Generic),
+        ("progress".into(), AriaRole::Progressbar),
+        ("q".into(), AriaRole::Generic),
+        ("s".into(), AriaRole::Deletion),
+        ("samp".into(), AriaRole::Generic),
+        ("search".into(), AriaRole::Search),
+        ("small".into(), AriaRole::Generic),
+        ("span".into(), AriaRole::Generic),
+        ("strong".into(), AriaRole::Strong),
+        ("sub".into(), AriaRole::Subscript),
+        ("summary".into(), AriaRole::Button),
+        ("sup".into(), AriaRole::Superscript),
+        ("svg".into(), AriaRole::GraphicsDocument),
+        ("table".into(), AriaRole::Table),
+        ("tbody".into(), AriaRole::Rowgroup),
+        ("textarea".into(), AriaRole::Textbox),
+        ("tfoot".into(), AriaRole::Rowgroup),
+        ("thead".into(), AriaRole::Rowgroup),
+        ("time".into(), AriaRole::Time),
+        ("tr".into(), AriaRole::Row),
+        ("u".into(), AriaRole::Generic),
+        ("ul".into(), AriaRole::List),
     ])
 });
 
@@ -85,26 +104,26 @@ const GLOBAL_ARIA_ATTRIBUTES: [&str; 18] = [
     "aria-roledescription",
 ];
 
-static PROHIBITED_ATTRIBUTES: LazyLock<HashMap<String, Vec<String>>> = LazyLock::new(|| {
+static PROHIBITED_ATTRIBUTES: LazyLock<HashMap<AriaRole, Vec<String>>> = LazyLock::new(|| {
     HashMap::from([
         (
-            "caption".to_string(),
+            AriaRole::Caption,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
         (
-            "code".to_string(),
+            AriaRole::Code,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
         (
-            "deletion".to_string(),
+            AriaRole::Deletion,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
         (
-            "emphasis".to_string(),
+            AriaRole::Emphasis,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
         (
-            "generic".to_string(),
+            AriaRole::Generic,
             vec![
                 "aria-label".to_string(),
                 "aria-labelledby".to_string(),
@@ -112,61 +131,77 @@ static PROHIBITED_ATTRIBUTES: LazyLock<HashMap<String, Vec<String>>> = LazyLock:
             ],
         ),
         (
-            "insertion".to_string(),
+            AriaRole::Insertion,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
         (
-            "none".to_string(),
+            AriaRole::None,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
         (
-            "paragraph".to_string(),
+            AriaRole::Paragraph,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
         (
-            "presentation".to_string(),
+            AriaRole::Presentation,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
         (
-            "strong".to_string(),
+            AriaRole::Strong,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
         (
-            "subscript".to_string(),
+            AriaRole::Subscript,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
         (
-            "superscript".to_string(),
+            AriaRole::Superscript,
             vec!["aria-label".to_string(), "aria-labelledby".to_string()],
         ),
     ])
 });
 
-fn has_global_aria_attributes(element: &Element, role: String) -> bool {
+fn has_global_aria_attributes(element: &Element, role: Option<AriaRole>) -> bool {
     GLOBAL_ARIA_ATTRIBUTES.iter().any(|attribute_name| {
         element.has_attribute(attribute_name)
-            && !PROHIBITED_ATTRIBUTES
-                .get(&role)
+            && !role
+                .and_then(|role| PROHIBITED_ATTRIBUTES.get(&role))
                 .is_some_and(|attributes| attributes.contains(&attribute_name.to_string()))
     })
 }
 
-fn ignore_presentational_role(element: &Element, implicit_role: String) -> bool {
+fn ignore_presentational_role(element: &Element, implicit_role: Option<AriaRole>) -> bool {
     // https://rawgit.com/w3c/aria/stable/#conflict_resolution_presentation_none
     has_global_aria_attributes(element, implicit_role)
 }
 
+/// Get the role of the element as a string.
+///
+/// See [`get_aria_role`].
 pub fn get_role(element: &Element) -> Option<String> {
+    role_string(element, get_aria_role(element))
+}
+
+/// String of the role of the element, including roles which are not ARIA roles.
+pub fn role_string(element: &Element, role: Option<AriaRole>) -> Option<String> {
+    role.map(|role| role.to_string()).or_else(|| {
+        // https://w3c.github.io/html-aam/#el-legend
+        // No corresponding ARIA role, but exposed by the platform accessibility APIs.
+        (element.local_name() == "legend").then(|| "legend".into())
+    })
+}
+
+/// Get the role of the element.
+///
+/// The explicit role is the first token of the `role` attribute which is a known ARIA role. Otherwise, the implicit
+/// role from html-aam is used.
+pub fn get_aria_role(element: &Element) -> Option<AriaRole> {
     let explicit_role = get_explicit_role(element);
     if explicit_role.is_none()
-        || explicit_role
-            .as_ref()
-            .is_some_and(|explicit_role| PRESENTATION_ROLES.contains(&explicit_role.as_str()))
+        || explicit_role.is_some_and(|explicit_role| PRESENTATION_ROLES.contains(&explicit_role))
     {
         let implicit_role = get_implicit_role(element);
-        if explicit_role.is_none()
-            || ignore_presentational_role(element, implicit_role.clone().unwrap_or("".into()))
-        {
+        if explicit_role.is_none() || ignore_presentational_role(element, implicit_role) {
             return implicit_role;
         }
     }
@@ -174,65 +209,222 @@ pub fn get_role(element: &Element) -> Option<String> {
     explicit_role
 }
 
-fn get_implicit_role(element: &Element) -> Option<String> {
+/// Whether the element has an accessible name provided by the author.
+///
+/// Only checks the attributes, because computing the accessible name depends on the role.
+fn has_author_name(element: &Element) -> bool {
+    ["aria-label", "aria-labelledby", "title"]
+        .iter()
+        .any(|attribute_name| {
+            element
+                .get_attribute(attribute_name)
+                .is_some_and(|value| !value.trim().is_empty())
+        })
+}
+
+fn has_ancestor(element: &Element, selectors: &str) -> bool {
+    element
+        .parent_element()
+        .and_then(|parent| {
+            parent
+                .closest(selectors)
+                .expect("Selectors should be valid.")
+        })
+        .is_some()
+}
+
+// https://w3c.github.io/html-aam/#el-header
+// https://w3c.github.io/html-aam/#el-footer
+fn is_scoped_to_body(element: &Element) -> bool {
+    !has_ancestor(
+        element,
+        "article, aside, main, nav, section, [role=\"article\"], [role=\"complementary\"], \
+        [role=\"main\"], [role=\"navigation\"], [role=\"region\"]",
+    )
+}
+
+// https://w3c.github.io/html-aam/#el-td
+// https://w3c.github.io/html-aam/#el-th
+fn get_table_cell_role(element: &Element) -> Option<AriaRole> {
+    let table = element
+        .closest("table")
+        .expect("Selectors should be valid.")?;
+    let table_role = get_aria_role(&table);
+    if table_role.is_some_and(|table_role| PRESENTATION_ROLES.contains(&table_role)) {
+        return None;
+    }
+    let is_grid = matches!(table_role, Some(AriaRole::Grid | AriaRole::Treegrid));
+
+    if element.local_name() == "th" {
+        let scope = element
+            .get_attribute("scope")
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let row = element.parent_element();
+
+        let is_row_header = match scope.as_str() {
+            "row" | "rowgroup" => true,
+            "col" | "colgroup" => false,
+            // Auto state: headers in the table head are column headers, headers next to data cells are row headers.
+            _ => {
+                let in_table_head = row
+                    .as_ref()
+                    .and_then(|row| row.parent_element())
+                    .is_some_and(|row_group| row_group.local_name() == "thead");
+                let next_to_data_cells = row.is_some_and(|row| {
+                    html_collection_to_vec::<Element>(row.children())
+                        .iter()
+                        .any(|cell| cell.local_name() == "td")
+                });
+
+                !in_table_head && next_to_data_cells
+            }
+        };
+
+        Some(if is_row_header {
+            AriaRole::Rowheader
+        } else {
+            AriaRole::Columnheader
+        })
+    } else {
+        Some(if is_grid {
+            AriaRole::Gridcell
+        } else {
+            AriaRole::Cell
+        })
+    }
+}
+
+fn get_implicit_role(element: &Element) -> Option<AriaRole> {
     let local_name = element.local_name();
 
     if let Some(mapped_by_tag) = LOCAL_NAME_TO_ROLE_MAPPINGS.get(&local_name) {
-        return Some(mapped_by_tag.clone());
+        return Some(*mapped_by_tag);
     }
 
     match local_name.as_str() {
-        "a" | "area" | "link" => element.has_attribute("href").then_some("link".into()),
+        "a" | "area" | "link" => {
+            if element.has_attribute("href") {
+                Some(AriaRole::Link)
+            } else if local_name == "a" {
+                Some(AriaRole::Generic)
+            } else {
+                None
+            }
+        }
+        "aside" => {
+            if has_author_name(element) || !has_ancestor(element, "article, aside, nav, section") {
+                Some(AriaRole::Complementary)
+            } else {
+                Some(AriaRole::Generic)
+            }
+        }
+        "footer" => {
+            if is_scoped_to_body(element) {
+                Some(AriaRole::Contentinfo)
+            } else {
+                Some(AriaRole::Generic)
+            }
+        }
+        "form" => {
+            if has_author_name(element) {
+                Some(AriaRole::Form)
+            } else {
+                Some(AriaRole::Generic)
+            }
+        }
+        "header" => {
+            if is_scoped_to_body(element) {
+                Some(AriaRole::Banner)
+            } else {
+                Some(AriaRole::Generic)
+            }
+        }
         "img" => {
             if element.get_attribute("alt") == Some("".into())
-                && !ignore_presentational_role(element, "img".into())
+                && !ignore_presentational_role(element, Some(AriaRole::Img))
             {
-                Some("presentation".into())
+                Some(AriaRole::Presentation)
             } else {
-                Some("img".into())
+                Some(AriaRole::Img)
             }
         }
         "input" => {
             let r#type = element.unchecked_ref::<HtmlInputElement>().type_();
             match r#type.as_str() {
-                "button" | "image" | "reset" | "submit" => Some("button".into()),
-                "checkbox" | "radio" => Some(r#type),
-                "range" => Some("slider".into()),
-                "email" | "tel" | "text" | "url" => {
+                "button" | "image" | "reset" | "submit" => Some(AriaRole::Button),
+                "checkbox" => Some(AriaRole::Checkbox),
+                "radio" => Some(AriaRole::Radio),
+                "range" => Some(AriaRole::Slider),
+                "search" => {
                     if element.has_attribute("list") {
-                        Some("combobox".into())
+                        Some(AriaRole::Combobox)
                     } else {
-                        Some("textbox".into())
+                        Some(AriaRole::Searchbox)
                     }
                 }
-                "search" => {
+                "number" => Some(AriaRole::Spinbutton),
+                // No corresponding role.
+                "color" | "date" | "datetime-local" | "file" | "hidden" | "month" | "password"
+                | "time" | "week" => None,
+                // Email, tel, text, url and invalid types, which fall back to text.
+                _ => {
                     if element.has_attribute("list") {
-                        Some("combobox".into())
+                        Some(AriaRole::Combobox)
                     } else {
-                        Some("searchbox".into())
+                        Some(AriaRole::Textbox)
                     }
                 }
-                "number" => Some("spinbutton".into()),
-                _ => None,
+            }
+        }
+        "li" => {
+            let in_list = element
+                .parent_element()
+                .is_some_and(|parent| matches!(parent.local_name().as_str(), "menu" | "ol" | "ul"));
+
+            Some(if in_list {
+                AriaRole::Listitem
+            } else {
+                AriaRole::Generic
+            })
+        }
+        "option" => {
+            // https://w3c.github.io/html-aam/#el-option
+            let in_list_of_options = element.parent_element().is_some_and(|parent| {
+                matches!(parent.local_name().as_str(), "datalist" | "select")
+                    || (parent.local_name() == "optgroup"
+                        && parent
+                            .parent_element()
+                            .is_some_and(|select| select.local_name() == "select"))
+            });
+
+            in_list_of_options.then_some(AriaRole::Option)
+        }
+        "section" => {
+            if has_author_name(element) {
+                Some(AriaRole::Region)
+            } else {
+                Some(AriaRole::Generic)
             }
         }
         "select" => {
             if element.has_attribute("multiple")
-                && element.unchecked_ref::<HtmlSelectElement>().size() > 1
+                || element.unchecked_ref::<HtmlSelectElement>().size() > 1
             {
-                Some("listbox".into())
+                Some(AriaRole::Listbox)
             } else {
-                Some("combobox".into())
+                Some(AriaRole::Combobox)
             }
         }
+        "td" | "th" => get_table_cell_role(element),
         _ => None,
     }
 }
 
-fn get_explicit_role(element: &Element) -> Option<String> {
-    element.get_attribute("role").and_then(|role| {
-        role.trim().split(' ').next().and_then(|explicit_role| {
-            (!explicit_role.is_empty()).then_some(explicit_role.to_string())
-        })
-    })
+/// The first token of the `role` attribute which is a known ARIA role.
+pub fn get_explicit_role(element: &Element) -> Option<AriaRole> {
+    element
+        .get_attribute("role")?
+        .split_ascii_whitespace()
+        .find_map(|explicit_role| explicit_role.parse().ok())
 }
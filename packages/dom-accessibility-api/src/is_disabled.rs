@@ -0,0 +1,75 @@
+use aria_query::AriaProperty;
+use web_sys::Element;
+
+use crate::{
+    get_role::get_aria_role,
+    util::{html_collection_to_vec, is_form_associated_custom_element, role_definition},
+};
+
+/// Form controls which can be disabled with the `disabled` attribute.
+///
+/// See <https://html.spec.whatwg.org/multipage/forms.html#enabling-and-disabling-form-controls:-the-disabled-attribute>.
+const DISABLEABLE_FORM_CONTROLS: [&str; 4] = ["button", "input", "select", "textarea"];
+
+fn first_legend_child(fieldset: &Element) -> Option<Element> {
+    html_collection_to_vec::<Element>(fieldset.children())
+        .into_iter()
+        .find(|child| child.local_name() == "legend")
+}
+
+/// Whether an ancestor `fieldset` with a `disabled` attribute disables the element.
+///
+/// Descendants of the first `legend` child of such a `fieldset` are not disabled by it.
+fn is_disabled_by_fieldset(element: &Element) -> bool {
+    let mut ancestor = element.parent_element();
+
+    while let Some(fieldset) = ancestor {
+        if fieldset.local_name() == "fieldset"
+            && fieldset.has_attribute("disabled")
+            && !first_legend_child(&fieldset).is_some_and(|legend| legend.contains(Some(element)))
+        {
+            return true;
+        }
+
+        ancestor = fieldset.parent_element();
+    }
+
+    false
+}
+
+/// Implements <https://html.spec.whatwg.org/multipage/semantics-other.html#concept-element-disabled>.
+fn is_actually_disabled(element: &Element) -> bool {
+    let local_name = element.local_name();
+
+    if DISABLEABLE_FORM_CONTROLS.contains(&local_name.as_str())
+        || local_name == "fieldset"
+        || is_form_associated_custom_element(element, None)
+    {
+        // https://html.spec.whatwg.org/multipage/form-elements.html#concept-fieldset-disabled
+        element.has_attribute("disabled") || is_disabled_by_fieldset(element)
+    } else if local_name == "optgroup" {
+        element.has_attribute("disabled")
+    } else if local_name == "option" {
+        // https://html.spec.whatwg.org/multipage/form-elements.html#concept-option-disabled
+        element.has_attribute("disabled")
+            || element.parent_element().is_some_and(|parent| {
+                parent.local_name() == "optgroup" && parent.has_attribute("disabled")
+            })
+    } else {
+        false
+    }
+}
+
+/// Whether the element is disabled.
+///
+/// Follows the "actually disabled" rules of HTML, including form-associated custom elements, and honors
+/// `aria-disabled="true"` for roles supporting it.
+pub fn is_disabled(element: &Element) -> bool {
+    is_actually_disabled(element)
+        || (element.get_attribute("aria-disabled") == Some("true".into())
+            && get_aria_role(element).is_some_and(|role| {
+                role_definition(role)
+                    .props
+                    .contains_key(&AriaProperty::AriaDisabled)
+            }))
+}
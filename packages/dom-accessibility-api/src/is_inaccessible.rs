@@ -0,0 +1,68 @@
+use std::rc::Rc;
+
+use web_sys::Element;
+
+use crate::{accessible_name_and_description::GetComputedStyle, util::default_get_computed_style};
+
+pub type IsSubtreeInaccessible = Rc<dyn Fn(&Element, &GetComputedStyle) -> bool>;
+
+/// Options for [`is_inaccessible`].
+#[derive(Clone, Default)]
+pub struct IsInaccessibleOptions {
+    /// Mock `window.get_computed_style`. Needs `display` and `visibility`.
+    pub get_computed_style: Option<GetComputedStyle>,
+
+    /// Replaces [`is_subtree_inaccessible`], e.g. to cache results while checking many elements.
+    pub is_subtree_inaccessible: Option<IsSubtreeInaccessible>,
+}
+
+/// Whether the element and its descendants are excluded from the accessibility tree.
+///
+/// Checks `hidden`, `aria-hidden="true"` and `display: none` on the element only.
+pub fn is_subtree_inaccessible(element: &Element, get_computed_style: &GetComputedStyle) -> bool {
+    element.has_attribute("hidden")
+        || element.get_attribute("aria-hidden") == Some("true".into())
+        || get_computed_style(element, None)
+            .get_property_value("display")
+            .expect("Computed style should have display.")
+            == "none"
+}
+
+/// Whether the element has `visibility: hidden`, which is inherited by its descendants.
+pub(crate) fn is_visibility_hidden(
+    element: &Element,
+    get_computed_style: &GetComputedStyle,
+) -> bool {
+    get_computed_style(element, None)
+        .get_property_value("visibility")
+        .expect("Computed style should have visibility.")
+        == "hidden"
+}
+
+/// Whether the element is excluded from the accessibility tree.
+///
+/// See <https://www.w3.org/TR/wai-aria-1.2/#tree_exclusion>.
+pub fn is_inaccessible(element: &Element, options: IsInaccessibleOptions) -> bool {
+    let get_computed_style = options
+        .get_computed_style
+        .unwrap_or_else(default_get_computed_style);
+    let is_subtree_inaccessible = options
+        .is_subtree_inaccessible
+        .unwrap_or_else(|| Rc::new(is_subtree_inaccessible));
+
+    // Visibility is inherited, so checking the element itself is enough.
+    if is_visibility_hidden(element, &get_computed_style) {
+        return true;
+    }
+
+    let mut current_element = Some(element.clone());
+    while let Some(element) = current_element {
+        if is_subtree_inaccessible(&element, &get_computed_style) {
+            return true;
+        }
+
+        current_element = element.parent_element();
+    }
+
+    false
+}
@@ -1,14 +1,26 @@
 mod accessible_description;
 mod accessible_name;
 mod accessible_name_and_description;
+mod caches;
+mod compute_session;
+mod computed_style_cache;
 mod get_role;
+mod is_disabled;
+mod is_inaccessible;
+mod locale;
 mod util;
 
 pub use accessible_description::*;
 pub use accessible_name::*;
 pub use accessible_name_and_description::{
-    Compute, ComputeTextAlternativeOptions, GetComputedStyle,
+    AccessibleNameAndDescription, Compute, ComputeTextAlternativeOptions, GetComputedStyle,
+    IsFormAssociated, compute_accessible_name_and_description,
 };
-pub use get_role::get_role;
-
-// TODO: is_disabled, is_inaccessible
+pub use compute_session::ComputeSession;
+pub use computed_style_cache::ComputedStyleCache;
+pub use get_role::{get_aria_role, get_role};
+pub use is_disabled::is_disabled;
+pub use is_inaccessible::{
+    IsInaccessibleOptions, IsSubtreeInaccessible, is_inaccessible, is_subtree_inaccessible,
+};
+pub use locale::{BuiltInDefaultNames, DefaultName, DefaultNameProvider};
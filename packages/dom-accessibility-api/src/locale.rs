@@ -0,0 +1,243 @@
+use std::rc::Rc;
+
+/// Names used by browsers for buttons without a value, see <https://w3c.github.io/html-aam/#input-type-button-input-type-submit-and-input-type-reset-accessible-name-computation>.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DefaultName {
+    /// Name of `<input type="submit">`.
+    Submit,
+    /// Name of `<input type="reset">`.
+    Reset,
+    /// Name of `<input type="image">`.
+    SubmitQuery,
+}
+
+/// Provides default names for locales.
+pub trait DefaultNameProvider {
+    /// Default name for a BCP 47 language tag, or `None` to fall back to the built-in tables.
+    fn default_name(&self, locale: &str, default_name: DefaultName) -> Option<String>;
+}
+
+/// Subtags of a lowercase BCP 47 language tag, see <https://www.rfc-editor.org/rfc/rfc5646#section-2.1>.
+struct LanguageTag<'a> {
+    language: &'a str,
+    script: Option<&'a str>,
+    region: Option<&'a str>,
+}
+
+impl<'a> LanguageTag<'a> {
+    fn parse(locale: &'a str) -> Self {
+        let mut subtags = locale.split('-');
+        let mut tag = LanguageTag {
+            language: subtags.next().unwrap_or_default(),
+            script: None,
+            region: None,
+        };
+
+        for subtag in subtags {
+            let is_alphabetic = subtag.chars().all(|c| c.is_ascii_alphabetic());
+
+            match subtag.len() {
+                // Extended language subtags are not used by the built-in tables.
+                3 if is_alphabetic => {}
+                4 if is_alphabetic && tag.script.is_none() && tag.region.is_none() => {
+                    tag.script = Some(subtag);
+                }
+                2 if is_alphabetic && tag.region.is_none() => tag.region = Some(subtag),
+                3 if subtag.chars().all(|c| c.is_ascii_digit()) && tag.region.is_none() => {
+                    tag.region = Some(subtag)
+                }
+                // Variants, extensions and private use subtags.
+                _ => break,
+            }
+        }
+
+        tag
+    }
+}
+
+/// Built-in default names for common locales, following the labels of browser form controls.
+///
+/// Like the English names, `Submit` and `Reset` follow Chromium and `SubmitQuery` follows Firefox:
+/// * Chromium: messages `IDS_FORM_SUBMIT_LABEL` and `IDS_FORM_RESET_LABEL` in
+///   `third_party/blink/public/strings/translations/blink_strings_<locale>.xtb` of
+///   <https://chromium.googlesource.com/chromium/src>.
+/// * Firefox: key `Submit` in `<locale>/dom/chrome/layout/HtmlForm.properties` of
+///   <https://github.com/mozilla-l10n/firefox-l10n>.
+///
+/// The locale of each source is listed next to the names.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BuiltInDefaultNames;
+
+impl DefaultNameProvider for BuiltInDefaultNames {
+    fn default_name(&self, locale: &str, default_name: DefaultName) -> Option<String> {
+        let locale = locale.to_ascii_lowercase().replace('_', "-");
+        let tag = LanguageTag::parse(&locale);
+
+        let names = match (tag.language, tag.script, tag.region) {
+            // Chromium `zh-TW`, Firefox `zh-TW`.
+            ("zh", Some("hant"), _) | ("zh", None, Some("hk" | "mo" | "tw")) => {
+                ["提交", "重設", "提交查詢"]
+            }
+            // Chromium `pt-PT`, Firefox `pt-PT`.
+            ("pt", _, Some("pt")) => ["Submeter", "Repor", "Submeter consulta"],
+            // Chromium `blink_strings.grd`, Firefox `en-US`.
+            ("en", _, _) => ["Submit", "Reset", "Submit Query"],
+            // Chromium `de`, Firefox `de`.
+            ("de", _, _) => ["Senden", "Zurücksetzen", "Anfrage senden"],
+            // Chromium `es`, Firefox `es-ES`.
+            ("es", _, _) => ["Enviar", "Restablecer", "Enviar consulta"],
+            // Chromium `fr`, Firefox `fr`.
+            ("fr", _, _) => ["Envoyer", "Réinitialiser", "Envoyer la requête"],
+            // Chromium `it`, Firefox `it`.
+            ("it", _, _) => ["Invia", "Reimposta", "Invia query"],
+            // Chromium `ja`, Firefox `ja`.
+            ("ja", _, _) => ["送信", "リセット", "クエリ送信"],
+            // Chromium `ko`, Firefox `ko`.
+            ("ko", _, _) => ["제출", "초기화", "쿼리 제출"],
+            // Chromium `nl`, Firefox `nl`.
+            ("nl", _, _) => ["Verzenden", "Opnieuw instellen", "Query verzenden"],
+            // Chromium `pl`, Firefox `pl`.
+            ("pl", _, _) => ["Wyślij", "Resetuj", "Wyślij zapytanie"],
+            // Chromium `pt-BR`, Firefox `pt-BR`.
+            ("pt", _, _) => ["Enviar", "Redefinir", "Enviar consulta"],
+            // Chromium `ru`, Firefox `ru`.
+            ("ru", _, _) => ["Отправить", "Сбросить", "Отправить запрос"],
+            // Chromium `sv`, Firefox `sv-SE`.
+            ("sv", _, _) => ["Skicka", "Återställ", "Skicka fråga"],
+            // Chromium `tr`, Firefox `tr`.
+            ("tr", _, _) => ["Gönder", "Sıfırla", "Sorgu gönder"],
+            // Chromium `zh-CN`, Firefox `zh-CN`.
+            ("zh", _, _) => ["提交", "重置", "提交查询"],
+            _ => return None,
+        };
+
+        Some(
+            match default_name {
+                DefaultName::Submit => names[0],
+                DefaultName::Reset => names[1],
+                DefaultName::SubmitQuery => names[2],
+            }
+            .into(),
+        )
+    }
+}
+
+/// Default names resolved for a locale.
+#[derive(Clone, Debug)]
+pub struct DefaultNames {
+    pub submit: String,
+    pub reset: String,
+    pub submit_query: String,
+}
+
+impl DefaultNames {
+    /// Resolves the names with the provider, falling back to the built-in tables and then to English.
+    pub fn new(locale: Option<&str>, provider: Option<&Rc<dyn DefaultNameProvider>>) -> Self {
+        let locale = locale.unwrap_or("en");
+        let resolve = |default_name| {
+            provider
+                .and_then(|provider| provider.default_name(locale, default_name))
+                .or_else(|| BuiltInDefaultNames.default_name(locale, default_name))
+                .or_else(|| BuiltInDefaultNames.default_name("en", default_name))
+                .expect("English default name should exist.")
+        };
+
+        DefaultNames {
+            submit: resolve(DefaultName::Submit),
+            reset: resolve(DefaultName::Reset),
+            submit_query: resolve(DefaultName::SubmitQuery),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submit(locale: &str) -> Option<String> {
+        BuiltInDefaultNames.default_name(locale, DefaultName::Submit)
+    }
+
+    fn reset(locale: &str) -> Option<String> {
+        BuiltInDefaultNames.default_name(locale, DefaultName::Reset)
+    }
+
+    struct GermanSubmitProvider;
+
+    impl DefaultNameProvider for GermanSubmitProvider {
+        fn default_name(&self, locale: &str, default_name: DefaultName) -> Option<String> {
+            (locale == "de" && default_name == DefaultName::Submit).then(|| "Abschicken".into())
+        }
+    }
+
+    #[test]
+    fn normalizes_case_and_separators() {
+        assert_eq!(Some("Senden".into()), submit("de"));
+        assert_eq!(Some("Senden".into()), submit("DE_de"));
+        assert_eq!(Some("Submeter".into()), submit("pt_PT"));
+        assert_eq!(Some("Submeter".into()), submit("PT-pt"));
+    }
+
+    #[test]
+    fn falls_back_to_language() {
+        assert_eq!(Some("Envoyer".into()), submit("fr-CA"));
+        assert_eq!(Some("Enviar".into()), submit("es-419"));
+        assert_eq!(Some("Enviar".into()), submit("pt-BR"));
+        assert_eq!(Some("Enviar".into()), submit("pt"));
+        assert_eq!(None, submit("xx"));
+        assert_eq!(None, submit(""));
+    }
+
+    #[test]
+    fn matches_chinese_script_and_region() {
+        for locale in [
+            "zh-TW",
+            "zh-HK",
+            "zh-MO",
+            "zh-Hant",
+            "zh-Hant-TW",
+            "zh-Hant-HK",
+            "zh_Hant_MO",
+        ] {
+            assert_eq!(Some("重設".into()), reset(locale), "{locale}");
+        }
+
+        for locale in [
+            "zh",
+            "zh-CN",
+            "zh-SG",
+            "zh-Hans",
+            "zh-Hans-CN",
+            "zh-Hans-HK",
+        ] {
+            assert_eq!(Some("重置".into()), reset(locale), "{locale}");
+        }
+    }
+
+    #[test]
+    fn ignores_variants_and_extensions() {
+        assert_eq!(Some("重設".into()), reset("zh-TW-u-nu-hanidec"));
+        assert_eq!(Some("Repor".into()), reset("pt-PT-x-private"));
+        assert_eq!(Some("Redefinir".into()), reset("pt-x-pt"));
+    }
+
+    #[test]
+    fn resolves_provider_then_built_in_then_english() {
+        let provider: Rc<dyn DefaultNameProvider> = Rc::new(GermanSubmitProvider);
+
+        let names = DefaultNames::new(Some("de"), Some(&provider));
+        assert_eq!("Abschicken", names.submit);
+        assert_eq!("Zurücksetzen", names.reset);
+        assert_eq!("Anfrage senden", names.submit_query);
+
+        let names = DefaultNames::new(Some("xx"), Some(&provider));
+        assert_eq!("Submit", names.submit);
+        assert_eq!("Reset", names.reset);
+        assert_eq!("Submit Query", names.submit_query);
+
+        let names = DefaultNames::new(None, None);
+        assert_eq!("Submit", names.submit);
+        assert_eq!("Reset", names.reset);
+        assert_eq!("Submit Query", names.submit_query);
+    }
+}
@@ -1,42 +1,85 @@
+use std::rc::Rc;
+
+use aria_query::{AriaNameFromSource, AriaRole, AriaRoleDefinition, AriaRoleDefinitionKey, ROLES};
 use web_sys::{
-    Document, DocumentFragment, Element, HtmlCollection, Node, NodeList, js_sys::Array,
+    Element, HtmlCollection, Node, NodeList,
+    js_sys::{Array, Reflect},
     wasm_bindgen::JsCast,
+    window,
 };
 
-use crate::get_role::get_role;
-
-pub const PRESENTATION_ROLES: [&str; 2] = ["presentation", "none"];
-
-pub fn query_id_refs(node: &Node, attribute_name: &str) -> Vec<Element> {
-    if let Some(element) = node.dyn_ref::<Element>() {
-        if let Some(attribute_value) = element.get_attribute(attribute_name) {
-            let ids = attribute_value.split(' ');
+use crate::{
+    accessible_name_and_description::{GetComputedStyle, IsFormAssociated},
+    caches::Caches,
+};
 
-            let root = element.get_root_node();
+pub const PRESENTATION_ROLES: [AriaRole; 2] = [AriaRole::Presentation, AriaRole::None];
 
-            type GetElementById = dyn Fn(&str) -> Option<Element>;
-            let get_element_by_id: Box<GetElementById> = if root.is_instance_of::<Document>() {
-                let document = root.unchecked_into::<Document>();
-                Box::new(move |element_id: &str| document.get_element_by_id(element_id))
-            } else if root.is_instance_of::<DocumentFragment>() {
-                let document_fragment = root.unchecked_into::<DocumentFragment>();
-                Box::new(move |element_id: &str| document_fragment.get_element_by_id(element_id))
-            } else {
-                unreachable!("Node should be Document or DocumentFragment.")
-            };
+pub fn default_get_computed_style() -> GetComputedStyle {
+    Rc::new(|element, pseudo_elt| {
+        let window = window().expect("Window should exist.");
 
-            ids.filter_map(get_element_by_id).collect()
+        if let Some(pseudo_elt) = pseudo_elt {
+            window.get_computed_style_with_pseudo_elt(element, pseudo_elt)
         } else {
-            vec![]
+            window.get_computed_style(element)
         }
-    } else {
-        vec![]
+        .expect("Element should be valid.")
+        .expect("Computed style should exist.")
+    })
+}
+
+pub fn has_any_concrete_roles(caches: &Caches, node: &Node, roles: Vec<AriaRole>) -> bool {
+    caches
+        .node_role(node)
+        .is_some_and(|role| roles.contains(&role))
+}
+
+/// Whether the element is an autonomous custom element whose definition is form-associated.
+///
+/// See <https://html.spec.whatwg.org/multipage/custom-elements.html#form-associated-custom-element>.
+pub fn is_form_associated_custom_element(
+    element: &Element,
+    is_form_associated: Option<&IsFormAssociated>,
+) -> bool {
+    if is_form_associated.is_some_and(|is_form_associated| is_form_associated(element)) {
+        return true;
     }
+
+    // Customized built-in elements can't be form-associated and are not found by their local name.
+    element
+        .owner_document()
+        .and_then(|document| document.default_view())
+        .map(|window| window.custom_elements().get(&element.local_name()))
+        .filter(|constructor| !constructor.is_undefined())
+        .and_then(|constructor| Reflect::get(&constructor, &"formAssociated".into()).ok())
+        .is_some_and(|form_associated| form_associated.is_truthy())
 }
 
-pub fn has_any_concrete_roles(node: &Node, roles: Vec<&str>) -> bool {
-    node.dyn_ref::<Element>()
-        .is_some_and(|element| get_role(element).is_some_and(|role| roles.contains(&role.as_str())))
+pub fn role_definition(role: AriaRole) -> &'static AriaRoleDefinition {
+    ROLES
+        .get(&AriaRoleDefinitionKey::from(role))
+        .expect("Role definition should exist.")
+}
+
+/// Corrections of the `name_from` data of aria-query, see [`name_from`].
+///
+/// * `none` has no `name_from`, but it is a synonym of `presentation`, which prohibits naming.
+/// * `graphics-object` and `rowgroup` allow naming from contents, but browsers and upstream only name them from the
+///   author, otherwise e.g. a table head would be named by all of its cells.
+const NAME_FROM_OVERRIDES: [(AriaRole, &[AriaNameFromSource]); 3] = [
+    (AriaRole::None, &[AriaNameFromSource::Prohibited]),
+    (AriaRole::GraphicsObject, &[AriaNameFromSource::Author]),
+    (AriaRole::Rowgroup, &[AriaNameFromSource::Author]),
+];
+
+/// Sources of the accessible name of the role, see <https://w3c.github.io/aria/#namecalculation>.
+pub fn name_from(role: AriaRole) -> &'static [AriaNameFromSource] {
+    NAME_FROM_OVERRIDES
+        .iter()
+        .find(|(override_role, _)| *override_role == role)
+        .map(|(_, name_from)| *name_from)
+        .unwrap_or_else(|| &role_definition(role).name_from)
 }
 
 pub fn array_to_vec<T: JsCast>(array: Array) -> Vec<T> {
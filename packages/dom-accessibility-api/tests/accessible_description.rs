@@ -0,0 +1,70 @@
+mod util;
+
+use dom_accessibility_api::{
+    ComputeTextAlternativeOptions, compute_accessible_description,
+    compute_accessible_name_and_description,
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn describedby() {
+    let element = render(
+        r#"<button id="test" aria-describedby="a b">Name</button><span id="a">Hello</span><span id="b">World</span>"#,
+    );
+
+    assert_eq!(
+        "Hello World",
+        compute_accessible_description(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn describedby_also_labelling() {
+    let element = render(
+        r#"<button id="test" aria-labelledby="label" aria-describedby="label"></button><span id="label">Hello</span>"#,
+    );
+
+    let result =
+        compute_accessible_name_and_description(&element, ComputeTextAlternativeOptions::default());
+    assert_eq!("Hello", result.name);
+    assert_eq!("Hello", result.description);
+}
+
+// https://github.com/web-platform-tests/wpt/blob/master/accname/manual/description_1.0_combobox-focusable-manual.html
+#[wasm_bindgen_test]
+fn combobox_focusable() {
+    let element = render(
+        r#"<div id="test" role="combobox" tabindex="0" title="Choose your language."><span> English </span></div>"#,
+    );
+
+    let result =
+        compute_accessible_name_and_description(&element, ComputeTextAlternativeOptions::default());
+    assert_eq!("Choose your language.", result.name);
+    assert_eq!("", result.description);
+}
+
+#[wasm_bindgen_test]
+fn select_title() {
+    let element = render(
+        r#"<select id="test" title="Choose your language."><option>English</option></select>"#,
+    );
+
+    let result =
+        compute_accessible_name_and_description(&element, ComputeTextAlternativeOptions::default());
+    assert_eq!("Choose your language.", result.name);
+    assert_eq!("", result.description);
+}
+
+#[wasm_bindgen_test]
+fn title_after_name_from_content() {
+    let element = render(r#"<button id="test" title="Hint">Name</button>"#);
+
+    let result =
+        compute_accessible_name_and_description(&element, ComputeTextAlternativeOptions::default());
+    assert_eq!("Name", result.name);
+    assert_eq!("Hint", result.description);
+}
@@ -0,0 +1,184 @@
+mod util;
+
+use std::rc::Rc;
+
+use dom_accessibility_api::{ComputeTextAlternativeOptions, compute_accessible_name};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use crate::util::{define_form_associated_custom_element, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn label_with_descendant_text() {
+    let element = render(
+        r#"<input id="test" type="checkbox"><label for="test"><span><em>Hello</em> World</span></label>"#,
+    );
+
+    assert_eq!(
+        "Hello World",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn root_inside_label() {
+    let element = render(r#"<label><span id="test" role="alert">x</span></label>"#);
+
+    assert_eq!(
+        "",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn root_inside_figcaption() {
+    let element = render(
+        r#"<figure><figcaption><div id="test" role="region">Caption</div></figcaption></figure>"#,
+    );
+
+    assert_eq!(
+        "",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn root_row_group() {
+    let element = render(r#"<table><thead id="test"><tr><th>Header</th></tr></thead></table>"#);
+
+    assert_eq!(
+        "",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn div_with_aria_label() {
+    let element = render(r#"<div id="test" aria-label="Close"></div>"#);
+
+    assert_eq!(
+        "Close",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn span_with_aria_labelledby() {
+    let element =
+        render(r#"<span id="test" aria-labelledby="label"></span><span id="label">Label</span>"#);
+
+    assert_eq!(
+        "Label",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn a_without_href_with_title() {
+    let element = render(r#"<a id="test" title="Title">Content</a>"#);
+
+    assert_eq!(
+        "Title",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn caption_with_aria_label() {
+    let element = render(r#"<table><caption id="test" aria-label="Caption"></caption></table>"#);
+
+    assert_eq!(
+        "Caption",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn explicit_generic_with_aria_label() {
+    let element = render(r#"<div id="test" role="generic" aria-label="Close"></div>"#);
+
+    assert_eq!(
+        "",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn image_with_empty_alt() {
+    let element = render(r#"<img id="test" alt="" title="Title">"#);
+
+    assert_eq!(
+        "",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn embedded_searchbox() {
+    let element = render(
+        r#"<label><input id="test" type="checkbox"> Search <input type="search" value="Query"></label>"#,
+    );
+
+    assert_eq!(
+        "Search Query",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn form_associated_custom_element_labels() {
+    define_form_associated_custom_element("x-labelled-control");
+
+    let element = render(
+        r#"<x-labelled-control id="test"></x-labelled-control><label for="test">For</label>"#,
+    );
+    assert_eq!(
+        "For",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn form_associated_custom_element_nested_label() {
+    define_form_associated_custom_element("x-labelled-control");
+
+    let element =
+        render(r#"<label>Nested <x-labelled-control id="test"></x-labelled-control></label>"#);
+    assert_eq!(
+        "Nested",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn undefined_custom_element_labels() {
+    let element = render(
+        r#"<x-undefined-control id="test"></x-undefined-control><label for="test">For</label>"#,
+    );
+
+    assert_eq!(
+        "",
+        compute_accessible_name(&element, ComputeTextAlternativeOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn is_form_associated_labels() {
+    let element = render(
+        r#"<x-predicate-control id="test"></x-predicate-control><label for="test">For</label>"#,
+    );
+
+    assert_eq!(
+        "For",
+        compute_accessible_name(
+            &element,
+            ComputeTextAlternativeOptions {
+                is_form_associated: Some(Rc::new(|element| {
+                    element.local_name() == "x-predicate-control"
+                })),
+                ..Default::default()
+            }
+        )
+    );
+}
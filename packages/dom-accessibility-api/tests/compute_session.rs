@@ -0,0 +1,126 @@
+mod util;
+
+use aria_query::AriaRole;
+use dom_accessibility_api::{ComputeSession, ComputeTextAlternativeOptions};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{Element, js_sys::Promise, wasm_bindgen::JsValue};
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn element_by_id(element: &Element, id: &str) -> Element {
+    element
+        .owner_document()
+        .expect("Document should exist.")
+        .get_element_by_id(id)
+        .expect("Element should exist.")
+}
+
+/// Moves ID `label` from the first label to the second, which changes the cached element per ID.
+fn move_label_id(element: &Element) {
+    let first = element_by_id(element, "label");
+    let second = element_by_id(element, "next-label");
+    first.set_id("previous-label");
+    second.set_id("label");
+}
+
+const LABELLED_BY_HTML: &str = r#"<button id="test" aria-labelledby="label"></button><span id="label">First</span><span id="next-label">Second</span>"#;
+
+/// Waits until pending microtasks, such as mutation observer callbacks, have run.
+async fn flush_microtasks() {
+    JsFuture::from(Promise::resolve(&JsValue::UNDEFINED))
+        .await
+        .expect("Promise should resolve.");
+}
+
+#[wasm_bindgen_test]
+fn caches_without_observing() {
+    let element = render(LABELLED_BY_HTML);
+    let session = ComputeSession::new(ComputeTextAlternativeOptions::default());
+
+    assert_eq!("First", session.name(&element));
+
+    move_label_id(&element);
+    assert_eq!("First", session.name(&element));
+
+    session.invalidate();
+    assert_eq!("Second", session.name(&element));
+}
+
+#[wasm_bindgen_test]
+fn invalidates_synchronously_before_the_observer_callback() {
+    let element = render(LABELLED_BY_HTML);
+    let session = ComputeSession::new(ComputeTextAlternativeOptions::default());
+    session.observe(&element.get_root_node());
+
+    assert_eq!("First", session.name(&element));
+
+    // The observer callback runs in a microtask, so the pending records are taken before computing.
+    move_label_id(&element);
+    assert_eq!("Second", session.name(&element));
+}
+
+#[wasm_bindgen_test]
+async fn invalidates_in_the_observer_callback() {
+    let element = render(LABELLED_BY_HTML);
+    let session = ComputeSession::new(ComputeTextAlternativeOptions::default());
+    session.observe(&element.get_root_node());
+
+    assert_eq!("First", session.name(&element));
+
+    move_label_id(&element);
+    flush_microtasks().await;
+    assert_eq!("Second", session.name(&element));
+}
+
+#[wasm_bindgen_test]
+fn invalidates_labels() {
+    let element = render(r#"<input id="test"><label id="label" for="test">First</label>"#);
+    let session = ComputeSession::new(ComputeTextAlternativeOptions::default());
+    session.observe(&element.get_root_node());
+
+    assert_eq!("First", session.name(&element));
+
+    let label = element_by_id(&element, "label");
+    label
+        .set_attribute("for", "other")
+        .expect("Attribute should be set.");
+    assert_eq!("", session.name(&element));
+
+    label
+        .set_attribute("for", "test")
+        .expect("Attribute should be set.");
+    assert_eq!("First", session.name(&element));
+}
+
+#[wasm_bindgen_test]
+fn invalidates_roles() {
+    let element = render(r#"<div id="test" role="button"></div>"#);
+    let session = ComputeSession::new(ComputeTextAlternativeOptions::default());
+    session.observe(&element.get_root_node());
+
+    assert_eq!(Some(AriaRole::Button), session.aria_role(&element));
+
+    element
+        .set_attribute("role", "checkbox")
+        .expect("Attribute should be set.");
+    assert_eq!(Some(AriaRole::Checkbox), session.aria_role(&element));
+    assert_eq!(Some("checkbox".into()), session.role(&element));
+}
+
+#[wasm_bindgen_test]
+fn observes_while_a_clone_exists() {
+    let element = render(LABELLED_BY_HTML);
+    let session = ComputeSession::new(ComputeTextAlternativeOptions::default());
+    session.observe(&element.get_root_node());
+
+    let clone = session.clone();
+    drop(session);
+
+    // Still observed by the clone.
+    assert_eq!("First", clone.name(&element));
+    move_label_id(&element);
+    assert_eq!("Second", clone.name(&element));
+}
@@ -0,0 +1,102 @@
+mod util;
+
+use std::{cell::Cell, rc::Rc};
+
+use dom_accessibility_api::{
+    ComputeTextAlternativeOptions, ComputedStyleCache, GetComputedStyle, compute_accessible_name,
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::window;
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Computes styles with the window, counting the calls.
+fn counting_get_computed_style() -> (GetComputedStyle, Rc<Cell<usize>>) {
+    let calls = Rc::new(Cell::new(0));
+
+    let get_computed_style: GetComputedStyle = Rc::new({
+        let calls = calls.clone();
+
+        move |element, pseudo_elt| {
+            calls.set(calls.get() + 1);
+
+            let window = window().expect("Window should exist.");
+            if let Some(pseudo_elt) = pseudo_elt {
+                window.get_computed_style_with_pseudo_elt(element, pseudo_elt)
+            } else {
+                window.get_computed_style(element)
+            }
+            .expect("Element should be valid.")
+            .expect("Computed style should exist.")
+        }
+    });
+
+    (get_computed_style, calls)
+}
+
+#[wasm_bindgen_test]
+fn reuses_styles_per_element_and_pseudo_element() {
+    let element = render(r#"<div id="test"></div><div id="other"></div>"#);
+    let other = element
+        .next_element_sibling()
+        .expect("Sibling should exist.");
+    let (get_computed_style, calls) = counting_get_computed_style();
+    let cache = ComputedStyleCache::new();
+
+    cache.get(&get_computed_style, &element, None);
+    cache.get(&get_computed_style, &element, None);
+    assert_eq!(1, calls.get());
+
+    cache.get(&get_computed_style, &element, Some("::before"));
+    cache.get(&get_computed_style, &element, Some("::before"));
+    assert_eq!(2, calls.get());
+
+    cache.get(&get_computed_style, &other, None);
+    assert_eq!(3, calls.get());
+}
+
+#[wasm_bindgen_test]
+fn clear() {
+    let element = render(r#"<div id="test"></div>"#);
+    let (get_computed_style, calls) = counting_get_computed_style();
+    let cache = ComputedStyleCache::new();
+
+    cache.get(&get_computed_style, &element, None);
+    cache.clear();
+    cache.get(&get_computed_style, &element, None);
+    assert_eq!(2, calls.get());
+}
+
+#[wasm_bindgen_test]
+fn shared_between_computations() {
+    let element = render(r#"<button id="test"><span>Hello</span> <span>World</span></button>"#);
+    let (get_computed_style, calls) = counting_get_computed_style();
+    let options = ComputeTextAlternativeOptions {
+        get_computed_style: Some(get_computed_style),
+        computed_style_cache: Some(ComputedStyleCache::new()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        "Hello World",
+        compute_accessible_name(&element, options.clone())
+    );
+    let first_calls = calls.get();
+    assert!(first_calls > 0);
+
+    assert_eq!(
+        "Hello World",
+        compute_accessible_name(&element, options.clone())
+    );
+    assert_eq!(first_calls, calls.get());
+
+    options
+        .computed_style_cache
+        .as_ref()
+        .expect("Cache should exist.")
+        .clear();
+    assert_eq!("Hello World", compute_accessible_name(&element, options));
+    assert_eq!(2 * first_calls, calls.get());
+}
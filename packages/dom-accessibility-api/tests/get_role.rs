@@ -0,0 +1,158 @@
+mod util;
+
+use aria_query::AriaRole;
+use dom_accessibility_api::{get_aria_role, get_role};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn legend() {
+    let element = render(r#"<fieldset><legend id="test">Legend</legend></fieldset>"#);
+
+    assert_eq!(Some("legend".into()), get_role(&element));
+    assert_eq!(None, get_aria_role(&element));
+}
+
+#[wasm_bindgen_test]
+fn legend_with_explicit_role() {
+    let element =
+        render(r#"<fieldset><legend id="test" role="heading">Legend</legend></fieldset>"#);
+
+    assert_eq!(Some("heading".into()), get_role(&element));
+}
+
+fn role_of(html: &str) -> Option<AriaRole> {
+    get_aria_role(&render(html))
+}
+
+#[wasm_bindgen_test]
+fn form() {
+    assert_eq!(
+        Some(AriaRole::Generic),
+        role_of(r#"<form id="test"></form>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Form),
+        role_of(r#"<form id="test" aria-label="Search"></form>"#)
+    );
+}
+
+#[wasm_bindgen_test]
+fn section() {
+    assert_eq!(
+        Some(AriaRole::Generic),
+        role_of(r#"<section id="test"></section>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Region),
+        role_of(r#"<section id="test" title="Region"></section>"#)
+    );
+}
+
+#[wasm_bindgen_test]
+fn aside() {
+    assert_eq!(
+        Some(AriaRole::Complementary),
+        role_of(r#"<aside id="test"></aside>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Generic),
+        role_of(r#"<article><aside id="test"></aside></article>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Complementary),
+        role_of(r#"<section><aside id="test" aria-label="Related"></aside></section>"#)
+    );
+}
+
+#[wasm_bindgen_test]
+fn header_and_footer() {
+    assert_eq!(
+        Some(AriaRole::Banner),
+        role_of(r#"<header id="test"></header>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Generic),
+        role_of(r#"<article><header id="test"></header></article>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Contentinfo),
+        role_of(r#"<footer id="test"></footer>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Generic),
+        role_of(r#"<div role="main"><footer id="test"></footer></div>"#)
+    );
+}
+
+#[wasm_bindgen_test]
+fn li() {
+    assert_eq!(
+        Some(AriaRole::Listitem),
+        role_of(r#"<ul><li id="test"></li></ul>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Generic),
+        role_of(r#"<div><li id="test"></li></div>"#)
+    );
+}
+
+#[wasm_bindgen_test]
+fn option() {
+    assert_eq!(
+        Some(AriaRole::Option),
+        role_of(r#"<select><optgroup><option id="test"></option></optgroup></select>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Option),
+        role_of(r#"<datalist><option id="test"></option></datalist>"#)
+    );
+    assert_eq!(None, role_of(r#"<div><option id="test"></option></div>"#));
+}
+
+#[wasm_bindgen_test]
+fn table_cells() {
+    assert_eq!(
+        Some(AriaRole::Cell),
+        role_of(r#"<table><tr><td id="test"></td></tr></table>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Gridcell),
+        role_of(r#"<table role="grid"><tr><td id="test"></td></tr></table>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Columnheader),
+        role_of(r#"<table><thead><tr><th id="test"></th></tr></thead></table>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Rowheader),
+        role_of(r#"<table role="grid"><tr><th id="test"></th><td></td></tr></table>"#)
+    );
+    assert_eq!(
+        None,
+        role_of(r#"<table role="presentation"><tr><td id="test"></td></tr></table>"#)
+    );
+}
+
+#[wasm_bindgen_test]
+fn select() {
+    assert_eq!(
+        Some(AriaRole::Combobox),
+        role_of(r#"<select id="test"></select>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Listbox),
+        role_of(r#"<select id="test" multiple></select>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Listbox),
+        role_of(r#"<select id="test" size="2"></select>"#)
+    );
+    assert_eq!(
+        Some(AriaRole::Combobox),
+        role_of(r#"<select id="test" size="1"></select>"#)
+    );
+}
@@ -0,0 +1,121 @@
+mod util;
+
+use dom_accessibility_api::is_disabled;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use crate::util::{define_form_associated_custom_element, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn is_rendered_disabled(html: &str) -> bool {
+    is_disabled(&render(html))
+}
+
+#[wasm_bindgen_test]
+fn form_controls() {
+    assert!(is_rendered_disabled(
+        r#"<button id="test" disabled></button>"#
+    ));
+    assert!(is_rendered_disabled(r#"<input id="test" disabled>"#));
+    assert!(is_rendered_disabled(
+        r#"<select id="test" disabled></select>"#
+    ));
+    assert!(is_rendered_disabled(
+        r#"<textarea id="test" disabled></textarea>"#
+    ));
+    assert!(!is_rendered_disabled(r#"<button id="test"></button>"#));
+    assert!(!is_rendered_disabled(r#"<div id="test" disabled></div>"#));
+}
+
+#[wasm_bindgen_test]
+fn fieldset() {
+    assert!(is_rendered_disabled(
+        r#"<fieldset id="test" disabled></fieldset>"#
+    ));
+    assert!(is_rendered_disabled(
+        r#"<fieldset disabled><div><input id="test"></div></fieldset>"#
+    ));
+    assert!(is_rendered_disabled(
+        r#"<fieldset disabled><fieldset id="test"></fieldset></fieldset>"#
+    ));
+    assert!(!is_rendered_disabled(
+        r#"<fieldset disabled><div id="test"></div></fieldset>"#
+    ));
+    assert!(!is_rendered_disabled(
+        r#"<fieldset><input id="test"></fieldset>"#
+    ));
+}
+
+#[wasm_bindgen_test]
+fn fieldset_legend() {
+    // Descendants of the first legend child are not disabled by the fieldset.
+    assert!(!is_rendered_disabled(
+        r#"<fieldset disabled><legend><input id="test"></legend></fieldset>"#
+    ));
+    assert!(is_rendered_disabled(
+        r#"<fieldset disabled><legend></legend><legend><input id="test"></legend></fieldset>"#
+    ));
+    assert!(is_rendered_disabled(
+        r#"<fieldset disabled><div><legend><input id="test"></legend></div></fieldset>"#
+    ));
+    // The legend of a nested fieldset does not escape the outer fieldset.
+    assert!(is_rendered_disabled(
+        r#"<fieldset disabled><fieldset><legend><input id="test"></legend></fieldset></fieldset>"#
+    ));
+}
+
+#[wasm_bindgen_test]
+fn option() {
+    assert!(is_rendered_disabled(
+        r#"<select><option id="test" disabled></option></select>"#
+    ));
+    assert!(is_rendered_disabled(
+        r#"<select><optgroup disabled><option id="test"></option></optgroup></select>"#
+    ));
+    assert!(is_rendered_disabled(
+        r#"<select><optgroup id="test" disabled></optgroup></select>"#
+    ));
+    assert!(!is_rendered_disabled(
+        r#"<select><optgroup><option id="test"></option></optgroup></select>"#
+    ));
+}
+
+#[wasm_bindgen_test]
+fn form_associated_custom_element() {
+    define_form_associated_custom_element("x-disabled-control");
+
+    assert!(is_rendered_disabled(
+        r#"<x-disabled-control id="test" disabled></x-disabled-control>"#
+    ));
+    assert!(is_rendered_disabled(
+        r#"<fieldset disabled><x-disabled-control id="test"></x-disabled-control></fieldset>"#
+    ));
+    assert!(!is_rendered_disabled(
+        r#"<x-disabled-control id="test"></x-disabled-control>"#
+    ));
+    // Not defined, so not form-associated.
+    assert!(!is_rendered_disabled(
+        r#"<x-undefined-control id="test" disabled></x-undefined-control>"#
+    ));
+}
+
+#[wasm_bindgen_test]
+fn aria_disabled() {
+    assert!(is_rendered_disabled(
+        r#"<div id="test" role="button" aria-disabled="true"></div>"#
+    ));
+    // Supported through the `input` super class.
+    assert!(is_rendered_disabled(
+        r#"<div id="test" role="checkbox" aria-disabled="true"></div>"#
+    ));
+    assert!(!is_rendered_disabled(
+        r#"<div id="test" role="button" aria-disabled="false"></div>"#
+    ));
+    // Not supported by `heading` and the implicit `generic` role.
+    assert!(!is_rendered_disabled(
+        r#"<div id="test" role="heading" aria-disabled="true"></div>"#
+    ));
+    assert!(!is_rendered_disabled(
+        r#"<div id="test" aria-disabled="true"></div>"#
+    ));
+}
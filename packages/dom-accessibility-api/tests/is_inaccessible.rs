@@ -0,0 +1,129 @@
+mod util;
+
+use std::rc::Rc;
+
+use dom_accessibility_api::{
+    GetComputedStyle, IsInaccessibleOptions, is_inaccessible, is_subtree_inaccessible,
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::window;
+
+use crate::util::render;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn get_computed_style() -> GetComputedStyle {
+    Rc::new(|element, _| {
+        window()
+            .expect("Window should exist.")
+            .get_computed_style(element)
+            .expect("Element should be valid.")
+            .expect("Computed style should exist.")
+    })
+}
+
+#[wasm_bindgen_test]
+fn accessible() {
+    let element = render(r#"<div><button id="test">Button</button></div>"#);
+
+    assert!(!is_inaccessible(&element, IsInaccessibleOptions::default()));
+    assert!(!is_subtree_inaccessible(&element, &get_computed_style()));
+}
+
+#[wasm_bindgen_test]
+fn hidden() {
+    let element = render(r#"<div id="test" hidden></div>"#);
+
+    assert!(is_inaccessible(&element, IsInaccessibleOptions::default()));
+    assert!(is_subtree_inaccessible(&element, &get_computed_style()));
+}
+
+#[wasm_bindgen_test]
+fn aria_hidden_on_ancestor() {
+    let element = render(r#"<div aria-hidden="true"><div><span id="test"></span></div></div>"#);
+
+    assert!(is_inaccessible(&element, IsInaccessibleOptions::default()));
+    assert!(!is_subtree_inaccessible(&element, &get_computed_style()));
+}
+
+#[wasm_bindgen_test]
+fn aria_hidden_false() {
+    let element = render(r#"<div aria-hidden="false"><span id="test"></span></div>"#);
+
+    assert!(!is_inaccessible(&element, IsInaccessibleOptions::default()));
+}
+
+#[wasm_bindgen_test]
+fn display_none() {
+    let element = render(r#"<div style="display: none"><span id="test"></span></div>"#);
+
+    assert!(is_inaccessible(&element, IsInaccessibleOptions::default()));
+}
+
+#[wasm_bindgen_test]
+fn visibility_hidden() {
+    let element = render(r#"<div style="visibility: hidden"><span id="test"></span></div>"#);
+
+    assert!(is_inaccessible(&element, IsInaccessibleOptions::default()));
+    // Visibility is not checked for the subtree, because descendants can be visible again.
+    assert!(!is_subtree_inaccessible(&element, &get_computed_style()));
+}
+
+#[wasm_bindgen_test]
+fn visible_inside_visibility_hidden() {
+    let element = render(
+        r#"<div style="visibility: hidden"><span id="test" style="visibility: visible"></span></div>"#,
+    );
+
+    assert!(!is_inaccessible(&element, IsInaccessibleOptions::default()));
+}
+
+#[wasm_bindgen_test]
+fn injected_get_computed_style() {
+    let element = render(r#"<div id="test"></div><div id="hidden" style="display: none"></div>"#);
+    let hidden = element
+        .owner_document()
+        .expect("Document should exist.")
+        .get_element_by_id("hidden")
+        .expect("Element should exist.");
+
+    // Every element gets the computed style of the hidden element.
+    let get_hidden_computed_style: GetComputedStyle =
+        Rc::new(move |_, pseudo_elt| get_computed_style()(&hidden, pseudo_elt));
+
+    assert!(is_inaccessible(
+        &element,
+        IsInaccessibleOptions {
+            get_computed_style: Some(get_hidden_computed_style.clone()),
+            ..Default::default()
+        }
+    ));
+    assert!(is_subtree_inaccessible(
+        &element,
+        &get_hidden_computed_style
+    ));
+}
+
+#[wasm_bindgen_test]
+fn injected_is_subtree_inaccessible() {
+    let element =
+        render(r#"<div data-inaccessible><div hidden><span id="test"></span></div></div>"#);
+    let options = IsInaccessibleOptions {
+        is_subtree_inaccessible: Some(Rc::new(|element, _| {
+            element.has_attribute("data-inaccessible")
+        })),
+        ..Default::default()
+    };
+
+    assert!(is_inaccessible(&element, options.clone()));
+
+    // The injected check replaces the default one, so `hidden` is ignored.
+    element
+        .parent_element()
+        .expect("Parent should exist.")
+        .parent_element()
+        .expect("Grandparent should exist.")
+        .remove_attribute("data-inaccessible")
+        .expect("Attribute should be removed.");
+    assert!(!is_inaccessible(&element, options));
+}
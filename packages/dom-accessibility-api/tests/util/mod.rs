@@ -0,0 +1,65 @@
+use std::ops::Deref;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+use web_sys::{Element, window};
+
+/// Element with ID `test` of a rendered container, which is removed from the document when dropped.
+///
+/// Removing the container keeps IDs unique in the document shared by all tests.
+pub struct Rendered {
+    container: Element,
+    element: Element,
+}
+
+impl Deref for Rendered {
+    type Target = Element;
+
+    fn deref(&self) -> &Element {
+        &self.element
+    }
+}
+
+impl Drop for Rendered {
+    fn drop(&mut self) {
+        self.container.remove();
+    }
+}
+
+/// Renders the HTML in a new container in the document body and returns the element with ID `test`.
+pub fn render(html: &str) -> Rendered {
+    let document = window()
+        .expect("Window should exist.")
+        .document()
+        .expect("Document should exist.");
+
+    let container = document
+        .create_element("div")
+        .expect("Element should be created.");
+    container.set_inner_html(html);
+    document
+        .body()
+        .expect("Body should exist.")
+        .append_child(&container)
+        .expect("Element should be appended.");
+
+    let element = container
+        .query_selector("#test")
+        .expect("Selector should be valid.")
+        .expect("Element with ID `test` should exist.");
+
+    Rendered { container, element }
+}
+
+#[wasm_bindgen(inline_js = "
+    export function define_form_associated_custom_element(name) {
+        if (!customElements.get(name)) {
+            customElements.define(name, class extends HTMLElement {
+                static formAssociated = true;
+            });
+        }
+    }
+")]
+extern "C" {
+    /// Defines an autonomous custom element with `static formAssociated = true`, unless it is already defined.
+    pub fn define_form_associated_custom_element(name: &str);
+}